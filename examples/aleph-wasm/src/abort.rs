@@ -0,0 +1,42 @@
+use futures::future::{self, Either, Future};
+use js_sys::{Error, Promise, Reflect};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::AbortSignal;
+
+/// The request was aborted before the app was rendered, e.g. the client disconnected.
+#[derive(Debug)]
+pub struct Aborted;
+
+/// Thrown to Aleph's server as an `Error` with the non-standard "Client Closed Request" status,
+/// the server doesn't report it to the `onError` handler.
+impl From<Aborted> for JsValue {
+  fn from(_: Aborted) -> Self {
+    let error = Error::new("Aborted");
+    _ = Reflect::set(&error, &"status".into(), &499.into());
+    error.into()
+  }
+}
+
+/// Returns a future that resolves when the signal is aborted, e.g. the client disconnected.
+pub fn aborted(signal: &AbortSignal) -> impl Future<Output = ()> {
+  let promise = Promise::new(&mut |resolve, _| {
+    if signal.aborted() {
+      _ = resolve.call0(&JsValue::UNDEFINED);
+    } else {
+      _ = signal.add_event_listener_with_callback("abort", &resolve);
+    }
+  });
+  let aborted = JsFuture::from(promise);
+  async move {
+    _ = aborted.await;
+  }
+}
+
+/// Runs the future until the signal is aborted, the future is dropped on abort.
+pub async fn abortable<F: Future>(signal: &AbortSignal, fut: F) -> Result<F::Output, Aborted> {
+  match future::select(Box::pin(fut), Box::pin(aborted(signal))).await {
+    Either::Left((output, _)) => Ok(output),
+    Either::Right(_) => Err(Aborted),
+  }
+}
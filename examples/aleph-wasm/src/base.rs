@@ -0,0 +1,70 @@
+//! The `router.basePath` option of the server, the app is served under it in the browser. It's an
+//! empty string if the app is served at the root, and has no trailing slash otherwise.
+
+/// Removes the base path from the path of a browser url, e.g. `/app/todos` to `/todos`.
+pub fn strip_base<'a>(path: &'a str, base_path: &str) -> &'a str {
+  if base_path.is_empty() {
+    return path;
+  }
+  match path.strip_prefix(base_path) {
+    Some("") => "/",
+    Some(rest) if rest.starts_with('/') => rest,
+    _ => path,
+  }
+}
+
+/// Prepends the base path to a path of the app, e.g. `/todos` to `/app/todos`. The external
+/// urls and the paths already under the base path are returned as-is.
+pub fn with_base(path: &str, base_path: &str) -> String {
+  let is_app_path = path.starts_with('/') && !path.starts_with("//");
+  if base_path.is_empty() || !is_app_path || strip_base(path, base_path) != path {
+    return path.to_owned();
+  }
+  match path {
+    "/" => base_path.to_owned(),
+    path => format!("{}{}", base_path, path),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn empty_base() {
+    assert_eq!(strip_base("/todos", ""), "/todos");
+    assert_eq!(strip_base("/", ""), "/");
+    assert_eq!(with_base("/todos", ""), "/todos");
+    assert_eq!(with_base("/", ""), "/");
+  }
+
+  #[test]
+  fn trailing_slash() {
+    assert_eq!(strip_base("/app", "/app"), "/");
+    assert_eq!(strip_base("/app/", "/app"), "/");
+    assert_eq!(strip_base("/app/todos/", "/app"), "/todos/");
+    assert_eq!(with_base("/", "/app"), "/app");
+    assert_eq!(with_base("/todos/", "/app"), "/app/todos/");
+  }
+
+  #[test]
+  fn path_not_under_base() {
+    assert_eq!(strip_base("/todos", "/app"), "/todos");
+    assert_eq!(strip_base("/application", "/app"), "/application");
+    assert_eq!(with_base("/application", "/app"), "/app/application");
+  }
+
+  #[test]
+  fn path_under_base() {
+    assert_eq!(strip_base("/app/todos/1", "/app"), "/todos/1");
+    assert_eq!(with_base("/todos/1", "/app"), "/app/todos/1");
+    assert_eq!(with_base("/app/todos/1", "/app"), "/app/todos/1");
+  }
+
+  #[test]
+  fn external_urls() {
+    assert_eq!(with_base("https://github.com/alephjs", "/app"), "https://github.com/alephjs");
+    assert_eq!(with_base("//cdn.example.com/logo.svg", "/app"), "//cdn.example.com/logo.svg");
+    assert_eq!(with_base("todos", "/app"), "todos");
+  }
+}
@@ -0,0 +1,86 @@
+use js_sys::{Array, Function, Object, Promise, Reflect};
+use wasm_bindgen::prelude::*;
+use web_sys::{AbortSignal, Headers, Url};
+
+#[wasm_bindgen]
+extern "C" {
+  /// The `SSRContext` object that Aleph's renderer passes to the `ssr` function.
+  #[derive(Clone, PartialEq)]
+  pub type SSRContext;
+
+  #[wasm_bindgen(method, getter)]
+  pub fn url(this: &SSRContext) -> Url;
+
+  #[wasm_bindgen(method, getter)]
+  pub fn headers(this: &SSRContext) -> Headers;
+
+  #[wasm_bindgen(method, getter)]
+  pub fn cookies(this: &SSRContext) -> Cookies;
+
+  #[wasm_bindgen(method, getter)]
+  pub fn params(this: &SSRContext) -> Object;
+
+  #[wasm_bindgen(method, getter)]
+  pub fn modules(this: &SSRContext) -> Array;
+
+  #[wasm_bindgen(method, getter, js_name = headCollection)]
+  pub fn head_collection(this: &SSRContext) -> Array;
+
+  #[wasm_bindgen(method, getter)]
+  pub fn signal(this: &SSRContext) -> AbortSignal;
+
+  /// The CSP nonce of the request. Aleph's renderer adds it to the inline scripts and styles
+  /// emitted by the app, the elements created at runtime, e.g. by a third-party script loader,
+  /// need it as well.
+  #[wasm_bindgen(method, getter)]
+  pub fn nonce(this: &SSRContext) -> Option<String>;
+
+  #[wasm_bindgen(method, getter, js_name = basePath)]
+  pub fn base_path(this: &SSRContext) -> String;
+
+  #[wasm_bindgen(method, js_name = getSession)]
+  pub fn get_session(this: &SSRContext) -> Promise;
+
+  #[wasm_bindgen(method, js_name = setStatus)]
+  pub fn set_status(this: &SSRContext, code: u16);
+
+  #[wasm_bindgen(method, js_name = setSuspenseMarker)]
+  pub fn set_suspense_marker(this: &SSRContext, selector: &str, test: &Function);
+
+  /// The cookies sent by the client.
+  pub type Cookies;
+
+  #[wasm_bindgen(method)]
+  pub fn get(this: &Cookies, key: &str) -> Option<String>;
+
+  /// The session of the request, see `server/session.ts`.
+  pub type Session;
+
+  #[wasm_bindgen(method, getter)]
+  pub fn store(this: &Session) -> JsValue;
+
+  /// The element passed to the handlers of Aleph's `HTMLRewriter`.
+  pub type HtmlRewriterElement;
+
+  #[wasm_bindgen(method)]
+  pub fn remove(this: &HtmlRewriterElement);
+}
+
+impl SSRContext {
+  /// Returns the value of the request header `name`.
+  pub fn header(&self, name: &str) -> Option<String> {
+    self.headers().get(name).ok().flatten()
+  }
+
+  /// Returns the value of the cookie `name`.
+  pub fn cookie(&self, name: &str) -> Option<String> {
+    self.cookies().get(name)
+  }
+
+  /// Returns the value of the route param `name`.
+  pub fn param(&self, name: &str) -> Option<String> {
+    Reflect::get(&self.params(), &JsValue::from_str(name))
+      .ok()
+      .and_then(|value| value.as_string())
+  }
+}
@@ -0,0 +1,7 @@
+//! The bindings of Aleph's server shared by the Rust examples, e.g. `examples/yew-app` and
+//! `examples/leptos-app`.
+
+pub mod abort;
+pub mod base;
+pub mod context;
+pub mod session;
@@ -0,0 +1,27 @@
+use js_sys::Promise;
+use serde::de::DeserializeOwned;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+use super::context::{SSRContext, Session};
+
+/// The id of the script that embeds the session values read on the server for the hydration.
+pub const SESSION_DATA_ID: &str = "ssr-session";
+
+/// Reads the session store of the request, e.g. the `user` placed by the GitHub OAuth middleware.
+///
+/// Returns `None` if the session is empty.
+pub async fn session_store<T: DeserializeOwned>(ctx: &SSRContext) -> Result<Option<T>, JsValue> {
+  read_store(ctx.get_session()).await
+}
+
+/// Reads the store of the session returned by the `getSession` method of a context.
+pub async fn read_store<T: DeserializeOwned>(session: Promise) -> Result<Option<T>, JsValue> {
+  let session: Session = JsFuture::from(session).await?.unchecked_into();
+  let store = session.store();
+  if store.is_undefined() || store.is_null() {
+    return Ok(None);
+  }
+  Ok(Some(serde_wasm_bindgen::from_value(store)?))
+}
@@ -1,11 +1,59 @@
 import { serve } from "aleph/server";
-import init, { ssr } from "./pkg/server.js";
+import init, {
+  adjust_counter,
+  clear_counter,
+  counter_events,
+  counter_value,
+  handle_server_fn,
+  ssr,
+  static_paths,
+} from "./pkg/server.js";
 
 const wasmUrl = new URL("./pkg/server_bg.wasm", import.meta.url);
 await init(await Deno.readFile(wasmUrl));
 
+// The API routes of the counter and the server functions, they are served by the wasm instance
+// initialised above since the counter state lives in it, see `src/counter.rs`.
+const api: Record<string, Record<string, unknown>> = {
+  "/api/counter": {
+    GET: () => Response.json(counter_value()),
+    // POST "/api/counter" with `{ "delta": 1 }`
+    POST: async (req: Request) => {
+      const { delta } = await req.json();
+      if (!Number.isInteger(delta)) {
+        return Response.json({ error: { message: "invalid delta", code: "invalidDelta" } }, { status: 400 });
+      }
+      return Response.json(await adjust_counter(delta));
+    },
+    DELETE: async () => Response.json(await clear_counter()),
+  },
+  // pushes the counter values to the browser as server-sent events
+  "/api/counter/events": {
+    GET: () =>
+      new Response(counter_events(), {
+        headers: {
+          "Content-Type": "text/event-stream",
+          "Cache-Control": "no-cache",
+        },
+      }),
+  },
+  // calls the `#[server]` function of the Leptos app, see `src/server_fns.rs`. The `hydrate` build
+  // posts to `/api/fn` at the root, so the server functions don't support a `basePath`.
+  "/api/fn/:name": {
+    POST: (req: Request, ctx: Context) => handle_server_fn(ctx.params.name, req),
+  },
+};
+
 serve({
-  ssr: ({ url }) => {
-    return ssr(url.href);
+  router: {
+    routes: Object.keys(api).map((pathname) => ({ filename: "", pattern: { pathname } })),
+    modules: api,
+  },
+  ssr: (ctx) => ssr(ctx),
+  build: {
+    // pre-render the pages with `deno run -A server.ts --build`
+    ssg: {
+      getStaticPaths: () => static_paths(),
+    },
   },
 });
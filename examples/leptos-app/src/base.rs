@@ -0,0 +1,55 @@
+use std::cell::RefCell;
+
+pub use aleph_wasm::base::{strip_base, with_base};
+
+/// The `router.basePath` option of the server, provided by the `ssr` and `hydrate` exports.
+///
+/// The app is served under it in the browser, it's an empty string for the root.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BasePath(pub String);
+
+thread_local! {
+    static STATIC_BASES: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+}
+
+/// Returns the base path for the `base` prop of `<Router/>`, which takes a static str.
+///
+/// The base path is leaked once per distinct value, it's the same for every request.
+pub fn static_base(base_path: &str) -> &'static str {
+    STATIC_BASES.with(|bases| {
+        let mut bases = bases.borrow_mut();
+        match bases.iter().find(|base| **base == base_path) {
+            Some(base) => base,
+            None => {
+                let base: &'static str = Box::leak(base_path.to_owned().into_boxed_str());
+                bases.push(base);
+                base
+            }
+        }
+    })
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "hydrate")] {
+        use leptos::document;
+        use serde::Deserialize;
+
+        /// The `router-manifest` script embedded by Aleph's renderer.
+        #[derive(Deserialize)]
+        struct RouterManifest {
+            #[serde(default, rename = "basePath")]
+            base_path: String,
+        }
+
+        /// Returns the base path embedded by the server.
+        pub fn embedded_base_path() -> BasePath {
+            let base_path = document()
+                .get_element_by_id("router-manifest")
+                .and_then(|el| el.text_content())
+                .and_then(|json| serde_json::from_str::<RouterManifest>(&json).ok())
+                .map(|manifest| manifest.base_path)
+                .unwrap_or_default();
+            BasePath(base_path)
+        }
+    }
+}
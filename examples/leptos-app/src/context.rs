@@ -0,0 +1,3 @@
+//! The `SSRContext` of Aleph's renderer, shared with the Yew example.
+
+pub use aleph_wasm::context::{SSRContext, Session};
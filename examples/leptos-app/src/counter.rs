@@ -0,0 +1,124 @@
+//! The counter shared by every browser, stored on the server since it can be modified by many
+//! users. The `ssr` build exports it to the `/api/counter` routes of `server.ts` and the server
+//! functions, the `hydrate` build calls the server functions and follows the updates pushed by the
+//! server.
+
+use leptos::*;
+
+/// The id of the element that renders the counter value, the browser reads the value rendered
+/// by the server from its `data-value` attribute on hydration.
+pub const COUNTER_ID: &str = "counter";
+
+/// A change of the counter requested by a browser.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Change {
+    Clear,
+    Adjust(i32),
+}
+
+// The server functions are posted to `/api/fn` at the root of the host, the `basePath` of the
+// router is not applied to them.
+
+/// Adds `delta` to the counter, returns the new value.
+#[server(AdjustCount, "/api/fn")]
+pub async fn adjust_count(delta: i32) -> Result<i32, ServerFnError> {
+    Ok(adjust_counter(delta).await)
+}
+
+/// Resets the counter, returns the new value.
+#[server(ClearCount, "/api/fn")]
+pub async fn clear_count() -> Result<i32, ServerFnError> {
+    Ok(clear_counter().await)
+}
+
+/// Sends the change to the server, returns the new value.
+pub async fn update(change: Change) -> Result<i32, ServerFnError> {
+    match change {
+        Change::Clear => clear_count().await,
+        Change::Adjust(delta) => adjust_count(delta).await,
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "ssr")] {
+        use broadcaster::BroadcastChannel;
+        use futures::stream::{self, StreamExt};
+        use js_sys::Uint8Array;
+        use std::sync::atomic::{AtomicI32, Ordering};
+        use wasm_bindgen::prelude::*;
+        use wasm_streams::ReadableStream;
+
+        static COUNT: AtomicI32 = AtomicI32::new(0);
+
+        lazy_static::lazy_static! {
+            static ref COUNT_CHANNEL: BroadcastChannel<i32> = BroadcastChannel::new();
+        }
+
+        /// Returns the current value of the counter.
+        pub fn value() -> i32 {
+            COUNT.load(Ordering::Relaxed)
+        }
+
+        async fn broadcast(value: i32) -> i32 {
+            _ = COUNT_CHANNEL.send(&value).await;
+            value
+        }
+
+        /// Returns the current value, for `GET /api/counter`.
+        #[wasm_bindgen]
+        pub fn counter_value() -> i32 {
+            value()
+        }
+
+        /// Adds `delta` to the counter and pushes the new value to the connected browsers, for
+        /// `POST /api/counter`.
+        #[wasm_bindgen]
+        pub async fn adjust_counter(delta: i32) -> i32 {
+            let value = COUNT.fetch_add(delta, Ordering::Relaxed) + delta;
+            broadcast(value).await
+        }
+
+        /// Resets the counter and pushes the new value to the connected browsers, for
+        /// `DELETE /api/counter`.
+        #[wasm_bindgen]
+        pub async fn clear_counter() -> i32 {
+            COUNT.store(0, Ordering::Relaxed);
+            broadcast(0).await
+        }
+
+        /// Streams the counter values as server-sent events, starting with the current value,
+        /// for `GET /api/counter/events`. The stream is dropped when the browser disconnects.
+        #[wasm_bindgen]
+        pub fn counter_events() -> JsValue {
+            let events = stream::once(async { value() })
+                .chain(COUNT_CHANNEL.clone())
+                .map(|value| Ok(Uint8Array::from(format!("data: {}\n\n", value).as_bytes()).into()));
+            ReadableStream::from_stream(events).into_raw().into()
+        }
+    } else if #[cfg(feature = "hydrate")] {
+        use futures::stream::{Stream, StreamExt};
+        use gloo_net::eventsource::futures::EventSource;
+
+        use crate::base::with_base;
+
+        /// Returns the counter value rendered by the server.
+        pub fn embedded_value() -> i32 {
+            document()
+                .get_element_by_id(COUNTER_ID)
+                .and_then(|el| el.get_attribute("data-value"))
+                .and_then(|value| value.parse().ok())
+                .unwrap_or_default()
+        }
+
+        /// Subscribes to the values pushed by the server, the subscription is closed when the
+        /// returned event source is dropped.
+        pub fn subscribe(base_path: &str) -> Option<(EventSource, impl Stream<Item = i32>)> {
+            let mut source = EventSource::new(&with_base("/api/counter/events", base_path)).ok()?;
+            let values = source.subscribe("message").ok()?.filter_map(|event| async move {
+                let (_, message) = event.ok()?;
+                message.data().as_string()?.parse().ok()
+            });
+            Some((source, values))
+        }
+    }
+}
@@ -3,35 +3,131 @@ cfg_if! {
     if #[cfg(feature = "hydrate")] {
         use wasm_bindgen::prelude::wasm_bindgen;
         use leptos::*;
+        pub mod base;
+        pub mod counter;
+        pub mod nonce;
         pub mod routes;
+        pub mod session;
+        pub mod status;
+        use base::embedded_base_path;
         use routes::{App, AppProps};
+        use session::{embedded_session, SessionStore};
 
         #[wasm_bindgen]
         pub fn hydrate() {
             console_error_panic_hook::set_once();
             _ = console_log::init_with_level(log::Level::Debug);
 
-            mount_to_body(|cx| {
+            let session = embedded_session::<SessionStore>();
+            let base_path = embedded_base_path();
+            mount_to_body(move |cx| {
+                provide_context(cx, session);
+                provide_context(cx, base_path);
                 view! { cx,  <App/> }
             })
         }
     }
 
     else if #[cfg(feature = "ssr")] {
-        use wasm_bindgen::prelude::wasm_bindgen;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+        use wasm_bindgen::prelude::*;
+        use web_sys::{Headers, Response, ResponseInit};
         use leptos::*;
-        use leptos_router::{ServerIntegration, RouterIntegrationContext};
+        use leptos_router::{provide_server_redirect, RouterIntegrationContext};
+        pub mod base;
+        pub mod context;
+        pub mod counter;
+        pub mod location;
+        pub mod nonce;
+        pub mod render;
         pub mod routes;
+        pub mod server_fns;
+        pub mod session;
+        pub mod status;
+        use aleph_wasm::abort::{abortable, Aborted};
+        use base::{with_base, BasePath};
+        use context::SSRContext;
+        use location::RequestLocation;
+        use js_sys::{Object, Reflect};
+        use nonce::Nonce;
+        use render::render_with_head;
         use routes::{App, AppProps};
+        use session::{render_session, session_store, SessionStore};
+        use status::SSRStatus;
+
+        /// Creates the response of a `<Redirect/>` rendered on the server, Aleph's server
+        /// responds with it as-is when it's thrown by the `ssr` export.
+        fn redirect_response(location: &str) -> Result<Response, JsValue> {
+            let headers = Headers::new()?;
+            headers.set("Location", location)?;
+            let init = ResponseInit::new();
+            init.set_status(302);
+            init.set_headers(&headers);
+            Response::new_with_opt_str_and_init(None, &init)
+        }
 
+        /// Returns the paths of the pages to pre-render.
         #[wasm_bindgen]
-        pub fn ssr(url: String) -> String {
-            let history = ServerIntegration { path: url };
-            let router_integration = RouterIntegrationContext::new(history);
-            render_to_string(move |cx| {
-                provide_context::<RouterIntegrationContext>(cx, router_integration);
-                view! { cx, <App/> }
-            })
+        pub fn static_paths() -> js_sys::Array {
+            routes::static_paths().into_iter().map(JsValue::from).collect()
+        }
+
+        /// Renders the request, the head tags registered with `leptos_meta` are returned in the
+        /// `head` of the result for Aleph's renderer to merge them into the `<head>`.
+        #[wasm_bindgen]
+        pub async fn ssr(ctx: SSRContext) -> Result<JsValue, JsValue> {
+            let base_path = ctx.base_path();
+            let url = ctx.url();
+            let location =
+                RequestLocation::with_base_path(&url.pathname(), &url.search(), &base_path);
+            let router_integration = location.router_integration();
+            let redirect: Rc<RefCell<Option<String>>> = Default::default();
+            let status = SSRStatus::default();
+            let signal = ctx.signal();
+            // the session is loaded before rendering since `render_to_string` is synchronous, a
+            // store that can't be read, e.g. shaped by another middleware, is treated as no session
+            let session = abortable(&signal, session_store::<SessionStore>(&ctx))
+                .await?
+                .ok()
+                .flatten();
+            // `render_to_string` can't be interrupted, skip it if the client has gone meanwhile
+            if signal.aborted() {
+                return Err(Aborted.into());
+            }
+            let nonce = ctx.nonce();
+            let (mut html, head) = render_with_head({
+                let redirect = redirect.clone();
+                let session = session.clone();
+                let status = status.clone();
+                move |cx| {
+                    provide_context::<RouterIntegrationContext>(cx, router_integration);
+                    provide_context(cx, session);
+                    provide_context(cx, status);
+                    provide_context(cx, BasePath(base_path.clone()));
+                    if let Some(nonce) = nonce {
+                        provide_context(cx, Nonce(nonce));
+                    }
+                    let base_path = base_path.clone();
+                    provide_server_redirect(cx, move |path| {
+                        redirect.borrow_mut().get_or_insert_with(|| with_base(path, &base_path));
+                    });
+                    view! { cx, <App/> }
+                }
+            });
+            if let Some(location) = redirect.take() {
+                return Err(redirect_response(&location)?.into());
+            }
+            if let Some(code) = status.0.get() {
+                ctx.set_status(code);
+            }
+            if let Some(session) = &session {
+                html.push_str(&render_session(session));
+            }
+            let result = Object::new();
+            Reflect::set(&result, &"head".into(), &head.into())?;
+            Reflect::set(&result, &"body".into(), &html.into())?;
+            Ok(result.into())
         }
     }
 }
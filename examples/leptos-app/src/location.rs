@@ -0,0 +1,48 @@
+use leptos_router::{RouterIntegrationContext, ServerIntegration};
+
+use crate::base::{strip_base, with_base};
+
+/// The location of a request, bridged to `leptos_router` on the server.
+///
+/// `leptos_router` parses the location of the `ServerIntegration` as a path relative to its own
+/// origin, so it must be given the path and the query of the request, not the absolute url.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RequestLocation {
+    /// The path of the request, e.g. `/users/42`.
+    pub path: String,
+    /// The query of the request without the leading `?`, e.g. `tab=posts`.
+    pub query: String,
+}
+
+impl RequestLocation {
+    pub fn new(path: impl Into<String>, query: impl Into<String>) -> Self {
+        let path = path.into();
+        let query = query.into();
+        Self {
+            path: if path.is_empty() { "/".to_owned() } else { path },
+            query: query.trim_start_matches('?').to_owned(),
+        }
+    }
+
+    /// Creates the location of a request under the base path of the app.
+    ///
+    /// The request path may come without the base path from a proxy, the routes match the
+    /// browser path under it.
+    pub fn with_base_path(pathname: &str, search: &str, base_path: &str) -> Self {
+        Self::new(with_base(strip_base(pathname, base_path), base_path), search)
+    }
+
+    /// Returns the `path?query` that the router matches against.
+    pub fn href(&self) -> String {
+        if self.query.is_empty() {
+            self.path.clone()
+        } else {
+            format!("{}?{}", self.path, self.query)
+        }
+    }
+
+    /// Creates the router integration to provide before rendering the app.
+    pub fn router_integration(&self) -> RouterIntegrationContext {
+        RouterIntegrationContext::new(ServerIntegration { path: self.href() })
+    }
+}
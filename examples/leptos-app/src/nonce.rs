@@ -0,0 +1,10 @@
+use leptos::*;
+
+/// The CSP nonce of the request, provided by the `ssr` export.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Nonce(pub String);
+
+/// Returns the CSP nonce of the request being rendered, or `None` in the browser.
+pub fn use_nonce(cx: Scope) -> Option<String> {
+    use_context::<Nonce>(cx).map(|nonce| nonce.0)
+}
@@ -0,0 +1,24 @@
+use leptos::leptos_dom::HydrationCtx;
+use leptos::*;
+use leptos_meta::{provide_meta_context, use_head};
+
+/// Renders the app to html like `render_to_string`, and returns the tags registered with
+/// `leptos_meta` alongside, e.g. by `<Title/>` and `<Meta/>`.
+///
+/// The tags are read after the body is rendered, so they're in the order of the components.
+pub fn render_with_head<F, N>(f: F) -> (String, String)
+where
+    F: FnOnce(Scope) -> N + 'static,
+    N: IntoView,
+{
+    let runtime = create_runtime();
+    HydrationCtx::reset_id();
+    let rendered = run_scope(runtime, move |cx| {
+        provide_meta_context(cx);
+        let body = f(cx).into_view(cx).render_to_string(cx).into_owned();
+        let head = use_head(cx).dehydrate();
+        (body, head)
+    });
+    runtime.dispose();
+    rendered
+}
@@ -2,21 +2,68 @@ use leptos::*;
 use leptos_meta::*;
 use leptos_router::*;
 
+use crate::base::{static_base, BasePath};
+use crate::session::use_session;
+
+pub mod _404;
+pub mod about;
 pub mod index;
+use _404::{NotFound, NotFoundProps};
+use about::{About, AboutProps};
 use index::{Counter, CounterProps};
 
+/// The paths of the pages in `<Routes>`, relative to the base path.
+const COUNTER_PATH: &str = "";
+const ABOUT_PATH: &str = "about";
+
+/// The pages without params, every one of them must be listed to be pre-rendered.
+const PAGES: [&str; 2] = [COUNTER_PATH, ABOUT_PATH];
+
+/// The paths of the pages, pre-rendered by Aleph's static site generation.
+pub fn static_paths() -> Vec<String> {
+    PAGES.iter().map(|path| format!("/{}", path)).collect()
+}
+
+/// Shows the logged-in user of the session.
+#[component]
+pub fn UserInfo(cx: Scope) -> impl IntoView {
+    let user = use_session(cx).and_then(|session| session.user);
+    user.map(|user| view! { cx,
+        <header>
+            <img src=user.avatar_url alt=user.login.clone() width="20" height="20"/>
+            <span>{user.login}</span>
+        </header>
+    })
+}
+
 #[component]
 pub fn App(cx: Scope) -> impl IntoView {
     provide_meta_context(cx);
+    let base_path = use_context::<BasePath>(cx).unwrap_or_default();
     view! {
         cx,
-        <Router>
+        <Meta name="description" content="The Fullstack Framework in Deno."/>
+        <Router base=static_base(&base_path.0)>
+            <UserInfo/>
+            <nav>
+                <A href="/">"Counter"</A>
+                <A href="/about">"About"</A>
+            </nav>
             <main>
                 <Routes>
-                    <Route path="" view=|cx| view! {
+                    <Route path=COUNTER_PATH view=|cx| view! {
                         cx,
                         <Counter/>
                     }/>
+                    <Route path=ABOUT_PATH view=|cx| view! {
+                        cx,
+                        <About/>
+                    }/>
+                    // the static routes take precedence over the catch-all route
+                    <Route path="*any" view=|cx| view! {
+                        cx,
+                        <NotFound/>
+                    }/>
                 </Routes>
             </main>
         </Router>
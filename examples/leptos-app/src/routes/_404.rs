@@ -0,0 +1,19 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use crate::status::use_status;
+
+/// The page of the paths that no route matches, responded with the 404 status.
+#[component]
+pub fn NotFound(cx: Scope) -> impl IntoView {
+    use_status(cx, 404);
+
+    view! { cx,
+        <Title text="Not Found - Leptos"/>
+        <div>
+            <h1>"Ooooooops, nothing here!"</h1>
+            <A href="/">"Go back to the homepage"</A>
+        </div>
+    }
+}
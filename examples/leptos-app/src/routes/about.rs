@@ -0,0 +1,16 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+/// The about page, navigated to from the index page without reloading.
+#[component]
+pub fn About(cx: Scope) -> impl IntoView {
+    view! { cx,
+        <Title text="About - Leptos"/>
+        <div>
+            <h1>"About"</h1>
+            <p>"A Leptos app rendered on the server by Aleph.js and hydrated in the browser."</p>
+            <A href="/">"Back to the counter"</A>
+        </div>
+    }
+}
@@ -1,21 +1,64 @@
 use leptos::*;
+use leptos_meta::*;
 
-/// A simple counter component.
-/// 
-/// You can use doc comments like this to document your component.
+#[cfg(feature = "hydrate")]
+use crate::base::BasePath;
+use crate::counter::{update, Change, COUNTER_ID};
+
+/// The counter shared by every browser, see `counter.rs`.
+///
+/// The value is rendered by the server, the buttons send the changes to the server which pushes
+/// the new value to every connected browser.
 #[component]
-pub fn Counter(
-    cx: Scope,
-) -> impl IntoView {
-    let (value, set_value) = create_signal(cx, 0);
-    let step = 1;
+pub fn Counter(cx: Scope) -> impl IntoView {
+    let (value, set_value) = create_signal(cx, initial_value());
+
+    #[cfg(feature = "hydrate")]
+    {
+        use futures::StreamExt;
+
+        let base_path = use_context::<BasePath>(cx).unwrap_or_default().0;
+        if let Some((source, mut values)) = crate::counter::subscribe(&base_path) {
+            spawn_local(async move {
+                while let Some(value) = values.next().await {
+                    set_value(value);
+                }
+            });
+            // closes the subscription when the page is left
+            on_cleanup(cx, move || drop(source));
+        }
+    }
+
+    // the changes are sent with the typed server functions of `counter.rs`
+    let on_click = move |change: Change| {
+        move |_| {
+            spawn_local(async move {
+                if let Ok(value) = update(change).await {
+                    set_value(value);
+                }
+            });
+        }
+    };
 
     view! { cx,
+        <Title text="Counter - Leptos"/>
         <div>
-            <button on:click=move |_| set_value(0)>"Clear"</button>
-            <button on:click=move |_| set_value.update(|value| *value -= step)>"-1"</button>
-            <span>"Value: " {value} "!"</span>
-            <button on:click=move |_| set_value.update(|value| *value += step)>"+1"</button>
+            <button on:click=on_click(Change::Clear)>"Clear"</button>
+            <button on:click=on_click(Change::Adjust(-1))>"-1"</button>
+            <span id=COUNTER_ID data-value=move || value().to_string()>"Value: " {value} "!"</span>
+            <button on:click=on_click(Change::Adjust(1))>"+1"</button>
         </div>
     }
 }
+
+fn initial_value() -> i32 {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "ssr")] {
+            crate::counter::value()
+        } else if #[cfg(feature = "hydrate")] {
+            crate::counter::embedded_value()
+        } else {
+            0
+        }
+    }
+}
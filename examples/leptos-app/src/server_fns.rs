@@ -0,0 +1,60 @@
+use js_sys::Uint8Array;
+use leptos::*;
+use std::sync::Once;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Headers, Request, Response, ResponseInit};
+
+use crate::counter::{AdjustCount, ClearCount};
+
+static REGISTER: Once = Once::new();
+
+/// Registers the `#[server]` functions of the app, every server function must be listed here
+/// to be callable from the browser.
+fn register_server_fns() {
+    REGISTER.call_once(|| {
+        _ = AdjustCount::register();
+        _ = ClearCount::register();
+    });
+}
+
+fn response(status: u16, content_type: &str, body: &[u8]) -> Result<Response, JsValue> {
+    let headers = Headers::new()?;
+    headers.set("Content-Type", content_type)?;
+    let init = ResponseInit::new();
+    init.set_status(status);
+    init.set_headers(&headers);
+    let mut body = body.to_vec();
+    Response::new_with_opt_u8_array_and_init(Some(&mut body), &init)
+}
+
+/// Calls the server function `name` with the arguments in the request body, for the
+/// `/api/fn/:name` route of `server.ts` that the server functions of the `hydrate` build post to.
+///
+/// The request is provided to the server function as context, e.g. to read the cookies.
+#[wasm_bindgen]
+pub async fn handle_server_fn(name: String, req: Request) -> Result<Response, JsValue> {
+    register_server_fns();
+    let server_fn = match server_fn_by_path(&name) {
+        Some(server_fn) => server_fn,
+        None => {
+            let message = format!("Server function `{}` not found", name);
+            return response(404, "text/plain", message.as_bytes());
+        }
+    };
+    let body = Uint8Array::new(&JsFuture::from(req.array_buffer()?).await?).to_vec();
+
+    let runtime = create_runtime();
+    let (cx, disposer) = raw_scope_and_disposer(runtime);
+    provide_context(cx, req);
+    let result = server_fn(cx, &body).await;
+    disposer.dispose();
+    runtime.dispose();
+
+    match result {
+        Ok(Payload::Binary(data)) => response(200, "application/cbor", &data),
+        Ok(Payload::Url(data)) => response(200, "application/x-www-form-urlencoded", data.as_bytes()),
+        Ok(Payload::Json(data)) => response(200, "application/json", data.as_bytes()),
+        Err(err) => response(500, "text/plain", err.to_string().as_bytes()),
+    }
+}
@@ -0,0 +1,46 @@
+use leptos::*;
+use serde::{Deserialize, Serialize};
+
+pub use aleph_wasm::session::SESSION_DATA_ID;
+
+/// The user placed in the session by the GitHub OAuth middleware.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct User {
+    pub login: String,
+    pub avatar_url: String,
+}
+
+/// The session store of the app.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SessionStore {
+    pub user: Option<User>,
+}
+
+/// Returns the session store provided by the `ssr` and `hydrate` exports.
+pub fn use_session(cx: Scope) -> Option<SessionStore> {
+    use_context::<Option<SessionStore>>(cx).flatten()
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "ssr")] {
+        pub use aleph_wasm::session::session_store;
+
+        /// Renders the script that embeds the session store in the html.
+        pub fn render_session<T: Serialize>(store: &T) -> String {
+            let json = serde_json::to_string(store).unwrap_or_default();
+            format!(
+                r#"<script type="application/json" id="{}">{}</script>"#,
+                SESSION_DATA_ID,
+                json.replace('<', "\\u003c")
+            )
+        }
+    } else if #[cfg(feature = "hydrate")] {
+        use serde::de::DeserializeOwned;
+
+        /// Returns the session store embedded by the server.
+        pub fn embedded_session<T: DeserializeOwned>() -> Option<T> {
+            let el = document().get_element_by_id(SESSION_DATA_ID)?;
+            serde_json::from_str(&el.text_content()?).ok()
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use leptos::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// The status code requested by the pages while the app is rendered on the server, the `ssr`
+/// export passes it to `SSRContext.setStatus`.
+#[derive(Clone, Debug, Default)]
+pub struct SSRStatus(pub Rc<Cell<Option<u16>>>);
+
+/// Sets the HTTP status code of the response when the component is rendered on the server,
+/// e.g. `use_status(cx, 404)` in a not-found page.
+pub fn use_status(cx: Scope, code: u16) {
+    if let Some(status) = use_context::<SSRStatus>(cx) {
+        status.0.set(Some(code));
+    }
+}
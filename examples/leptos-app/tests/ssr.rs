@@ -0,0 +1,147 @@
+#![cfg(feature = "ssr")]
+
+use leptos::*;
+use leptos_app::location::RequestLocation;
+use leptos_app::render::render_with_head;
+use leptos_app::routes::{App, AppProps};
+use leptos_app::status::SSRStatus;
+use leptos_router::*;
+
+#[component]
+fn Home(cx: Scope) -> impl IntoView {
+    view! { cx, <h1>"Home page"</h1> }
+}
+
+#[component]
+fn About(cx: Scope) -> impl IntoView {
+    view! { cx, <h1>"About page"</h1> }
+}
+
+#[component]
+fn User(cx: Scope) -> impl IntoView {
+    let id = use_params_map(cx).with(|params| params.get("id").cloned().unwrap_or_default());
+    let tab = use_query_map(cx).with(|query| query.get("tab").cloned().unwrap_or_default());
+    view! { cx, <h1>{format!("User {} {}", id, tab)}</h1> }
+}
+
+#[component]
+fn TestApp(cx: Scope) -> impl IntoView {
+    view! {
+        cx,
+        <Router>
+            <Routes>
+                <Route path="" view=|cx| view! { cx, <Home/> }/>
+                <Route path="about" view=|cx| view! { cx, <About/> }/>
+                <Route path="users/:id" view=|cx| view! { cx, <User/> }/>
+            </Routes>
+        </Router>
+    }
+}
+
+#[component]
+fn BaseApp(cx: Scope) -> impl IntoView {
+    view! {
+        cx,
+        <Router base="/app">
+            <Routes>
+                <Route path="" view=|cx| view! { cx, <Home/> }/>
+                <Route path="about" view=|cx| view! { cx, <About/> }/>
+            </Routes>
+        </Router>
+    }
+}
+
+fn render(location: RequestLocation) -> String {
+    render_to_string(move |cx| {
+        provide_context::<RouterIntegrationContext>(cx, location.router_integration());
+        view! { cx, <TestApp/> }
+    })
+}
+
+#[test]
+fn href_of_request_location() {
+    assert_eq!(RequestLocation::new("", "").href(), "/");
+    assert_eq!(RequestLocation::new("/about", "").href(), "/about");
+    assert_eq!(RequestLocation::new("/users/42", "?tab=posts").href(), "/users/42?tab=posts");
+    assert_eq!(RequestLocation::with_base_path("/about", "", "/app").href(), "/app/about");
+    assert_eq!(RequestLocation::with_base_path("/app/about", "", "/app").href(), "/app/about");
+    assert_eq!(RequestLocation::with_base_path("/", "", "/app").href(), "/app");
+}
+
+#[test]
+fn renders_index_route() {
+    let html = render(RequestLocation::new("/", ""));
+    assert!(html.contains("Home page"));
+    assert!(!html.contains("About page"));
+}
+
+#[test]
+fn renders_static_route() {
+    let html = render(RequestLocation::new("/about", ""));
+    assert!(html.contains("About page"));
+    assert!(!html.contains("Home page"));
+}
+
+#[test]
+fn renders_route_with_params_and_query() {
+    let html = render(RequestLocation::new("/users/42", "?tab=posts"));
+    assert!(html.contains("User 42 posts"));
+    assert!(!html.contains("Home page"));
+}
+
+#[test]
+fn renders_no_route_for_unknown_path() {
+    let html = render(RequestLocation::new("/nothing/here", ""));
+    assert!(!html.contains("Home page"));
+    assert!(!html.contains("About page"));
+}
+
+#[test]
+fn renders_route_under_base_path() {
+    let location = RequestLocation::with_base_path("/about", "", "/app");
+    let html = render_to_string(move |cx| {
+        provide_context::<RouterIntegrationContext>(cx, location.router_integration());
+        view! { cx, <BaseApp/> }
+    });
+    assert!(html.contains("About page"));
+}
+
+fn render_app(location: RequestLocation) -> (String, String, Option<u16>) {
+    let status = SSRStatus::default();
+    let (html, head) = render_with_head({
+        let status = status.clone();
+        move |cx| {
+            provide_context::<RouterIntegrationContext>(cx, location.router_integration());
+            provide_context(cx, status);
+            view! { cx, <App/> }
+        }
+    });
+    (html, head, status.0.get())
+}
+
+#[test]
+fn app_renders_counter_page() {
+    let (html, head, status) = render_app(RequestLocation::new("/", ""));
+    assert!(html.contains("Value: "));
+    assert!(head.contains("Counter - Leptos"));
+    assert!(head.contains("The Fullstack Framework in Deno."));
+    assert!(!html.contains("Counter - Leptos"));
+    assert_eq!(status, None);
+}
+
+#[test]
+fn app_renders_about_page() {
+    let (html, head, status) = render_app(RequestLocation::new("/about", ""));
+    assert!(head.contains("About - Leptos"));
+    assert!(html.contains("rendered on the server by Aleph.js"));
+    assert!(!html.contains("Value: "));
+    assert_eq!(status, None);
+}
+
+#[test]
+fn app_renders_not_found_page_with_404() {
+    let (html, head, status) = render_app(RequestLocation::new("/nothing/here", ""));
+    assert!(head.contains("Not Found - Leptos"));
+    assert!(html.contains("nothing here"));
+    assert_eq!(status, Some(404));
+}
@@ -0,0 +1,143 @@
+//! The attributes of the API route handlers and the data loaders of the app, see `src/aleph/api.rs`.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, Error, Ident, ItemFn, LitStr, Token};
+
+/// Handles the `GET` requests of the route, e.g. `#[get("/api/users/:uid")]`.
+#[proc_macro_attribute]
+pub fn get(attr: TokenStream, item: TokenStream) -> TokenStream {
+  handler("GET", attr, item)
+}
+
+/// Handles the `POST` requests of the route.
+#[proc_macro_attribute]
+pub fn post(attr: TokenStream, item: TokenStream) -> TokenStream {
+  handler("POST", attr, item)
+}
+
+/// Handles the `PUT` requests of the route.
+#[proc_macro_attribute]
+pub fn put(attr: TokenStream, item: TokenStream) -> TokenStream {
+  handler("PUT", attr, item)
+}
+
+/// Handles the `PATCH` requests of the route.
+#[proc_macro_attribute]
+pub fn patch(attr: TokenStream, item: TokenStream) -> TokenStream {
+  handler("PATCH", attr, item)
+}
+
+/// Handles the `DELETE` requests of the route.
+#[proc_macro_attribute]
+pub fn delete(attr: TokenStream, item: TokenStream) -> TokenStream {
+  handler("DELETE", attr, item)
+}
+
+/// Loads the route data of the page, e.g. `#[data("/todos")]`, or `#[data("/todos/index", defer)]`
+/// to stream the page before the data is loaded.
+#[proc_macro_attribute]
+pub fn data(attr: TokenStream, item: TokenStream) -> TokenStream {
+  let DataArgs { path, defer } = parse_macro_input!(attr as DataArgs);
+  let mut func = parse_macro_input!(item as ItemFn);
+
+  if let Err(err) = check_handler(&path, &func) {
+    return err.to_compile_error().into();
+  }
+
+  let attrs = std::mem::take(&mut func.attrs);
+  let vis = std::mem::replace(&mut func.vis, syn::Visibility::Inherited);
+  let name = &func.sig.ident;
+
+  quote! {
+    #(#attrs)*
+    #[allow(non_camel_case_types)]
+    #vis struct #name;
+
+    impl crate::aleph::api::DataLoader for #name {
+      const PATH: &'static str = #path;
+      const DEFER: bool = #defer;
+
+      fn fetch(
+        req: ::web_sys::Request,
+        ctx: crate::aleph::api::Context,
+      ) -> crate::aleph::api::HandlerFuture {
+        #func
+        Box::pin(async move { crate::aleph::api::IntoApiResponse::into_api_response(#name(req, ctx).await) })
+      }
+    }
+  }
+  .into()
+}
+
+/// The `"/path"` or `"/path", defer` arguments of the `#[data]` attribute.
+struct DataArgs {
+  path: LitStr,
+  defer: bool,
+}
+
+impl Parse for DataArgs {
+  fn parse(input: ParseStream) -> syn::Result<Self> {
+    let path = input.parse()?;
+    let mut defer = false;
+    if input.parse::<Option<Token![,]>>()?.is_some() {
+      let ident: Ident = input.parse()?;
+      if ident != "defer" {
+        return Err(Error::new(ident.span(), "expected `defer`"));
+      }
+      defer = true;
+    }
+    Ok(Self { path, defer })
+  }
+}
+
+/// Checks the route path and the `async fn(Request, Context)` signature.
+fn check_handler(path: &LitStr, func: &ItemFn) -> syn::Result<()> {
+  if !path.value().starts_with('/') {
+    return Err(Error::new(path.span(), "the route path must start with `/`"));
+  }
+  if func.sig.asyncness.is_none() {
+    return Err(Error::new_spanned(func.sig.fn_token, "the route handler must be `async`"));
+  }
+  if func.sig.inputs.len() != 2 {
+    return Err(Error::new_spanned(&func.sig.inputs, "the route handler takes `(Request, Context)`"));
+  }
+  Ok(())
+}
+
+/// Turns the `async fn(Request, Context)` into a unit struct of the same name implementing
+/// `ApiHandler`, so it can be registered with `ApiRouter::service`.
+fn handler(method: &str, attr: TokenStream, item: TokenStream) -> TokenStream {
+  let path = parse_macro_input!(attr as LitStr);
+  let mut func = parse_macro_input!(item as ItemFn);
+
+  if let Err(err) = check_handler(&path, &func) {
+    return err.to_compile_error().into();
+  }
+
+  // the docs and the visibility go to the struct, the function is moved into `call`
+  let attrs = std::mem::take(&mut func.attrs);
+  let vis = std::mem::replace(&mut func.vis, syn::Visibility::Inherited);
+  let name = &func.sig.ident;
+
+  quote! {
+    #(#attrs)*
+    #[allow(non_camel_case_types)]
+    #vis struct #name;
+
+    impl crate::aleph::api::ApiHandler for #name {
+      const METHOD: &'static str = #method;
+      const PATH: &'static str = #path;
+
+      fn call(
+        req: ::web_sys::Request,
+        ctx: crate::aleph::api::Context,
+      ) -> crate::aleph::api::HandlerFuture {
+        #func
+        Box::pin(async move { crate::aleph::api::IntoApiResponse::into_api_response(#name(req, ctx).await) })
+      }
+    }
+  }
+  .into()
+}
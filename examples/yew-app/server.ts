@@ -1,9 +1,28 @@
 import { serve } from "aleph/server";
-import init, { ssr } from "./pkg/yew_app.js";
+import init, { api_routes, middlewares, routes, ssr_stream, static_paths } from "./pkg/yew_app.js";
 
 const wasmUrl = new URL("./pkg/yew_app_bg.wasm", import.meta.url);
 await init(await Deno.readFile(wasmUrl));
 
+// the API routes and the page data loaders of `src/api.rs`, served like the route modules of the `routes`
+// directory by the wasm instance initialised above
+const api = api_routes();
+
 serve({
-  ssr: ({ url }) => ssr(url.href),
+  router: {
+    // the routes of the `Route` enum in `src/routes.rs`
+    routes: [...routes(), ...api.routes],
+    modules: api.modules,
+  },
+  // the middlewares of `src/middlewares.rs`, they can be listed with the TS middlewares
+  middlewares: middlewares(),
+  ssr: (ctx) => ssr_stream(ctx),
+  build: {
+    // pre-render the pages with `deno run -A server.ts --build`
+    ssg: {
+      getStaticPaths: () => static_paths(),
+      // the todos pages embed the data of the API store, which is changed at runtime
+      exclude: /^\/todos/,
+    },
+  },
 });
@@ -0,0 +1,15 @@
+pub use aleph_wasm::abort;
+
+pub mod api;
+pub mod context;
+pub mod data;
+pub mod error;
+pub mod head;
+pub mod middleware;
+pub mod params;
+pub mod query;
+pub mod redirect;
+pub mod router;
+pub mod session;
+pub mod status;
+pub mod stream;
@@ -0,0 +1,283 @@
+use aleph_wasm::session::read_store;
+use futures::future::LocalBoxFuture;
+use js_sys::{Object, Promise, Reflect, JSON};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_wasm_bindgen::Serializer;
+use std::fmt;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::{future_to_promise, JsFuture};
+use web_sys::{FormData, Headers, Request, Response, ResponseInit};
+
+pub use aleph_macros::{data, delete, get, patch, post, put};
+
+use super::context::Cookies;
+use super::params::{FromParams, Params};
+use super::router::RouteMeta;
+
+#[wasm_bindgen]
+extern "C" {
+  /// The `Context` object that Aleph's server passes to the API route handlers and the
+  /// middlewares.
+  pub type Context;
+
+  #[wasm_bindgen(method, getter)]
+  pub fn params(this: &Context) -> Object;
+
+  #[wasm_bindgen(method, getter)]
+  pub fn cookies(this: &Context) -> Cookies;
+
+  #[wasm_bindgen(method, js_name = getSession)]
+  pub fn get_session(this: &Context) -> Promise;
+
+  #[wasm_bindgen(method, js_name = next)]
+  fn next_handler(this: &Context) -> JsValue;
+}
+
+impl Context {
+  /// Parses the params of the route, e.g. `{ "uid": "1" }` of `/api/users/:uid`.
+  pub fn parse_params<T: FromParams>(&self) -> Result<T, ApiError> {
+    T::from_params(&Params::from(self.params()))
+      .map_err(|err| ApiError::not_found("invalidParams", err.to_string()))
+  }
+
+  /// Returns the value of the cookie `name`.
+  pub fn cookie(&self, name: &str) -> Option<String> {
+    self.cookies().get(name)
+  }
+
+  /// Reads the session store of the request, returns `None` if the session is empty.
+  pub async fn session_store<T: DeserializeOwned>(&self) -> Result<Option<T>, JsValue> {
+    read_store(self.get_session()).await
+  }
+
+  /// Calls the next middleware or the route handler, returns its response.
+  pub async fn next(&self) -> Result<Response, JsValue> {
+    let res = JsFuture::from(Promise::resolve(&self.next_handler())).await?;
+    Ok(res.unchecked_into())
+  }
+}
+
+/// The error response of an API route, sent as `{ "error": { "message", "code" } }` like the
+/// routes of the `api-app` example.
+#[derive(Debug)]
+pub struct ApiError {
+  pub status: u16,
+  pub code: &'static str,
+  pub message: String,
+}
+
+impl ApiError {
+  pub fn new(status: u16, code: &'static str, message: impl Into<String>) -> Self {
+    Self {
+      status,
+      code,
+      message: message.into(),
+    }
+  }
+
+  pub fn bad_request(code: &'static str, message: impl Into<String>) -> Self {
+    Self::new(400, code, message)
+  }
+
+  pub fn not_found(code: &'static str, message: impl Into<String>) -> Self {
+    Self::new(404, code, message)
+  }
+}
+
+impl fmt::Display for ApiError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{} ({})", self.message, self.code)
+  }
+}
+
+/// The JS errors, e.g. of reading the request body, are internal errors.
+impl From<JsValue> for ApiError {
+  fn from(err: JsValue) -> Self {
+    let message = match err.dyn_ref::<js_sys::Error>() {
+      Some(err) => String::from(err.message()),
+      None => err.as_string().unwrap_or_else(|| "unknown error".to_owned()),
+    };
+    Self::new(500, "internalError", message)
+  }
+}
+
+#[derive(Serialize)]
+struct ErrorBody<'a> {
+  error: ErrorDetail<'a>,
+}
+
+#[derive(Serialize)]
+struct ErrorDetail<'a> {
+  message: &'a str,
+  code: &'a str,
+}
+
+/// Responds with the value as JSON.
+pub struct Json<T>(pub T);
+
+/// Creates a JSON response like `Response.json(value, { status })`.
+pub fn json_response<T: Serialize + ?Sized>(value: &T, status: u16) -> Result<Response, JsValue> {
+  let value = value.serialize(&Serializer::json_compatible())?;
+  let body = String::from(JSON::stringify(&value)?);
+  let headers = Headers::new()?;
+  headers.set("Content-Type", "application/json; charset=utf-8")?;
+  let init = ResponseInit::new();
+  init.set_status(status);
+  init.set_headers(&headers);
+  Response::new_with_opt_str_and_init(Some(&body), &init)
+}
+
+/// Parses the JSON body of the request, an invalid body is a 400 error.
+pub async fn json_body<T: DeserializeOwned>(req: &Request) -> Result<T, ApiError> {
+  let invalid = |_| ApiError::bad_request("invalidBody", "invalid JSON body");
+  let value = JsFuture::from(req.json()?).await.map_err(invalid)?;
+  serde_wasm_bindgen::from_value(value).map_err(|err| ApiError::bad_request("invalidBody", err.to_string()))
+}
+
+/// Reads the form data of the request, an invalid body is a 400 error.
+pub async fn form_data(req: &Request) -> Result<FormData, ApiError> {
+  let data = JsFuture::from(req.form_data()?)
+    .await
+    .map_err(|_| ApiError::bad_request("invalidBody", "invalid form data"))?;
+  Ok(data.unchecked_into())
+}
+
+/// The value returned by an API route handler.
+pub trait IntoApiResponse {
+  fn into_api_response(self) -> Result<Response, JsValue>;
+}
+
+impl IntoApiResponse for Response {
+  fn into_api_response(self) -> Result<Response, JsValue> {
+    Ok(self)
+  }
+}
+
+impl<T: Serialize> IntoApiResponse for Json<T> {
+  fn into_api_response(self) -> Result<Response, JsValue> {
+    json_response(&self.0, 200)
+  }
+}
+
+impl IntoApiResponse for ApiError {
+  fn into_api_response(self) -> Result<Response, JsValue> {
+    let body = ErrorBody {
+      error: ErrorDetail {
+        message: &self.message,
+        code: self.code,
+      },
+    };
+    json_response(&body, self.status)
+  }
+}
+
+impl<T: IntoApiResponse, E: IntoApiResponse> IntoApiResponse for Result<T, E> {
+  fn into_api_response(self) -> Result<Response, JsValue> {
+    match self {
+      Ok(value) => value.into_api_response(),
+      Err(err) => err.into_api_response(),
+    }
+  }
+}
+
+pub type HandlerFuture = LocalBoxFuture<'static, Result<Response, JsValue>>;
+
+/// A handler of an API route, implemented by the `#[get]`, `#[post]`, `#[put]`, `#[patch]` and
+/// `#[delete]` attributes for an `async fn(Request, Context)`, e.g.
+///
+/// ```ignore
+/// #[get("/api/users/:uid")]
+/// async fn get_user(_req: Request, ctx: Context) -> Result<Json<User>, ApiError> { .. }
+/// ```
+pub trait ApiHandler: 'static {
+  /// The request method, e.g. `GET`.
+  const METHOD: &'static str;
+  /// The route pattern of Aleph's router, e.g. `/api/users/:uid`.
+  const PATH: &'static str;
+
+  fn call(req: Request, ctx: Context) -> HandlerFuture;
+}
+
+/// A loader of the route data of a page, implemented by the `#[data]` attribute for an
+/// `async fn(Request, Context)`, e.g.
+///
+/// ```ignore
+/// #[data("/todos/:list_id", defer)]
+/// async fn list_todos(_req: Request, ctx: Context) -> Json<TodosData> { .. }
+/// ```
+pub trait DataLoader: 'static {
+  /// The route pattern of the page, e.g. `/todos/:list_id`.
+  const PATH: &'static str;
+  /// Whether the page is streamed before the data is loaded, see `use_deferred_route_data`.
+  const DEFER: bool;
+
+  fn fetch(req: Request, ctx: Context) -> HandlerFuture;
+}
+
+/// The API routes of the app, each route is served like a route module exporting a function
+/// per method, e.g. `GET` and `PATCH` of `examples/api-app/routes/users/$uid.ts`.
+#[derive(Default)]
+pub struct ApiRouter {
+  modules: Vec<(&'static str, Object)>,
+}
+
+impl ApiRouter {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Returns the module of the route, adds it if it's the first handler of the route.
+  fn module(&mut self, pathname: &'static str) -> Object {
+    match self.modules.iter().find(|(p, _)| *p == pathname) {
+      Some((_, module)) => module.clone(),
+      None => {
+        let module = Object::new();
+        self.modules.push((pathname, module.clone()));
+        module
+      }
+    }
+  }
+
+  /// Adds the handler to the module of its route.
+  pub fn service<H: ApiHandler>(mut self, _handler: H) -> Self {
+    let module = self.module(H::PATH);
+    let handler = Closure::<dyn Fn(Request, Context) -> Promise>::new(|req, ctx| {
+      future_to_promise(async move { H::call(req, ctx).await.map(JsValue::from) })
+    });
+    _ = Reflect::set(&module, &H::METHOD.into(), &handler.into_js_value());
+    self
+  }
+
+  /// Adds the loader as the `data` export of its page, so the data is loaded by the same wasm
+  /// instance that serves the API routes.
+  pub fn data<D: DataLoader>(mut self, _loader: D) -> Self {
+    let module = self.module(D::PATH);
+    let fetch = Closure::<dyn Fn(Request, Context) -> Promise>::new(|req, ctx| {
+      future_to_promise(async move { D::fetch(req, ctx).await.map(JsValue::from) })
+    });
+    let data = Object::new();
+    _ = Reflect::set(&data, &"fetch".into(), &fetch.into_js_value());
+    _ = Reflect::set(&data, &"defer".into(), &D::DEFER.into());
+    _ = Reflect::set(&module, &"data".into(), &data);
+    self
+  }
+
+  /// Returns the `routes` and the `modules` to pass to the `router` options of the server.
+  pub fn into_js(self) -> Result<JsValue, JsValue> {
+    let metas = self
+      .modules
+      .iter()
+      .map(|(pathname, _)| RouteMeta::new(pathname.to_string()))
+      .collect::<Vec<_>>();
+    let modules = Object::new();
+    for (pathname, module) in &self.modules {
+      Reflect::set(&modules, &JsValue::from_str(pathname), module)?;
+    }
+    let ret = Object::new();
+    Reflect::set(&ret, &"routes".into(), &metas.serialize(&Serializer::json_compatible())?)?;
+    Reflect::set(&ret, &"modules".into(), &modules)?;
+    Ok(ret.into())
+  }
+}
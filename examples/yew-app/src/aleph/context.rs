@@ -0,0 +1,15 @@
+use yew::prelude::*;
+
+pub use aleph_wasm::context::{Cookies, HtmlRewriterElement, SSRContext, Session};
+
+/// Returns the `SSRContext` of the request being rendered, or `None` in the browser.
+#[hook]
+pub fn use_ssr_context() -> Option<SSRContext> {
+  use_context::<Option<SSRContext>>().flatten()
+}
+
+/// Returns the CSP nonce of the request being rendered, or `None` in the browser.
+#[hook]
+pub fn use_nonce() -> Option<String> {
+  use_ssr_context().and_then(|ctx| ctx.nonce())
+}
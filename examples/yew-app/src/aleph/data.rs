@@ -0,0 +1,211 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use js_sys::{Array, Function, Promise, Reflect, JSON};
+use serde::de::DeserializeOwned;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::Response;
+use yew::prelude::*;
+use yew::suspense::{Suspension, SuspensionResult};
+
+use super::abort::abortable;
+use super::context::{use_ssr_context, SSRContext};
+use super::router::{base_path, use_route_match, with_base};
+
+fn get(target: &JsValue, key: &str) -> JsValue {
+  Reflect::get(target, &JsValue::from_str(key)).unwrap_or(JsValue::UNDEFINED)
+}
+
+fn module_url(module: &JsValue) -> Option<String> {
+  let url = get(module, "url");
+  match url.as_string() {
+    Some(url) => Some(url),
+    None => {
+      let url = url.dyn_into::<web_sys::Url>().ok()?;
+      Some(format!("{}{}", url.pathname(), url.search()))
+    }
+  }
+}
+
+/// Returns the data of the route module of `data_url` fetched by Aleph during SSR.
+fn server_data(ctx: &SSRContext, data_url: &str) -> Option<JsValue> {
+  ctx
+    .modules()
+    .iter()
+    .find(|module| module_url(module).as_deref() == Some(data_url))
+    .filter(|module| get(module, "withData").is_truthy())
+    .map(|module| get(&module, "data"))
+}
+
+thread_local! {
+  /// The urls of the route modules whose embedded data has been read.
+  static CONSUMED: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Marks the embedded data of `data_url` as read, returns `false` if it was read before.
+///
+/// The embedded data is only used by the first hydration, a page remounted after navigation
+/// fetches its data with `?_data_` instead of reading the stale payload again.
+fn consume_embedded(data_url: &str) -> bool {
+  CONSUMED.with(|consumed| {
+    let mut consumed = consumed.borrow_mut();
+    if consumed.iter().any(|url| url == data_url) {
+      return false;
+    }
+    consumed.push(data_url.to_owned());
+    true
+  })
+}
+
+/// Returns the route data of `data_url` that Aleph embedded in the `ssr-data` script.
+fn embedded_data(data_url: &str) -> Option<JsValue> {
+  if !consume_embedded(data_url) {
+    return None;
+  }
+  let el = web_sys::window()?.document()?.get_element_by_id("ssr-data")?;
+  let modules: Array = JSON::parse(&el.text_content()?).ok()?.dyn_into().ok()?;
+  modules
+    .iter()
+    .find(|module| module_url(module).as_deref() == Some(data_url))
+    .filter(|module| get(module, "withData").is_truthy())
+    .map(|module| get(&module, "data"))
+}
+
+/// Resolves the deferred data of the route module of `data_url`, the `data` of the module is the
+/// fetcher function when the route module exports `data: { defer: true }`.
+async fn deferred_server_data(ctx: &SSRContext, data_url: &str) -> Option<JsValue> {
+  let data = server_data(ctx, data_url)?;
+  match data.dyn_ref::<Function>() {
+    Some(fetcher) => {
+      let res = fetcher.call0(&JsValue::NULL).ok()?;
+      JsFuture::from(Promise::resolve(&res)).await.ok()
+    }
+    None => Some(data),
+  }
+}
+
+/// Returns the deferred route data of `data_url` that Aleph appended to the streamed html in the
+/// `defered-data` script.
+async fn deferred_embedded_data(data_url: &str) -> Option<JsValue> {
+  if !consume_embedded(data_url) {
+    return None;
+  }
+  let document = web_sys::window()?.document()?;
+  if document.ready_state() == "loading" {
+    let loaded = Promise::new(&mut |resolve, _| {
+      _ = document.add_event_listener_with_callback("DOMContentLoaded", &resolve);
+    });
+    _ = JsFuture::from(loaded).await;
+  }
+  let el = document.get_element_by_id("defered-data")?;
+  let data = get(&JSON::parse(&el.text_content()?).ok()?, data_url);
+  if data.is_undefined() {
+    None
+  } else {
+    Some(data)
+  }
+}
+
+/// Fetches the route data of `data_url` from the server, like Aleph does on client navigation.
+async fn fetch_data(data_url: &str) -> Result<JsValue, JsValue> {
+  let window = web_sys::window().ok_or("no window")?;
+  let separator = if data_url.contains('?') { '&' } else { '?' };
+  let url = format!("{}{}_data_", with_base(data_url, &base_path(None)), separator);
+  let res: Response = JsFuture::from(window.fetch_with_str(&url))
+    .await?
+    .dyn_into()?;
+  if !res.ok() {
+    return Err(JsValue::from(res.status_text()));
+  }
+  JsFuture::from(res.json()?).await
+}
+
+fn deserialize<T: DeserializeOwned>(data: JsValue) -> Option<Rc<T>> {
+  serde_wasm_bindgen::from_value::<T>(data).ok().map(Rc::new)
+}
+
+/// Returns the data of the current route, which is defined by the `data` export of the route
+/// module in the `routes` directory.
+///
+/// Each route level reads the data of its own module, so a layout rendering an [`Outlet`](super::router::Outlet) and
+/// the nested route in the outlet load their data independently.
+///
+/// The data fetched by Aleph during SSR is rendered on the server and read from the `ssr-data`
+/// script on hydration, so both sides render the same markup. After client navigation the data
+/// is fetched from the server and `None` is returned until it's loaded, the embedded data is
+/// only read once.
+#[hook]
+pub fn use_route_data<T>() -> Option<Rc<T>>
+where
+  T: DeserializeOwned + 'static,
+{
+  let ctx = use_ssr_context();
+  let data_url = use_route_match().map(|route| route.url).unwrap_or_default();
+  let data = {
+    let data_url = data_url.clone();
+    use_state(move || {
+      let data = match ctx {
+        Some(ctx) => server_data(&ctx, &data_url),
+        None => embedded_data(&data_url),
+      };
+      (data_url, data.and_then(deserialize::<T>))
+    })
+  };
+
+  {
+    let data = data.clone();
+    use_effect_with_deps(
+      move |data_url: &String| {
+        if !data_url.is_empty() && (&data.0 != data_url || data.1.is_none()) {
+          let data_url = data_url.clone();
+          spawn_local(async move {
+            let value = fetch_data(&data_url).await.ok().and_then(deserialize::<T>);
+            data.set((data_url, value));
+          });
+        }
+        || ()
+      },
+      data_url,
+    );
+  }
+
+  data.1.clone()
+}
+
+/// Returns the data of the current route like [`use_route_data`], for the route modules that
+/// export `data: { defer: true }`.
+///
+/// The component suspends until the data is resolved: on the server while the rest of the page
+/// is streamed, in the browser until the `defered-data` script is loaded. Wrap the component
+/// in a `<Suspense>` to render a fallback meanwhile.
+#[hook]
+pub fn use_deferred_route_data<T>() -> SuspensionResult<Option<Rc<T>>>
+where
+  T: DeserializeOwned + 'static,
+{
+  let ctx = use_ssr_context();
+  let data_url = use_route_match().map(|route| route.url).unwrap_or_default();
+  let data = use_state(|| None::<(String, Option<Rc<T>>)>);
+
+  match &*data {
+    Some((url, value)) if url == &data_url => Ok(value.clone()),
+    _ => {
+      let data = data.clone();
+      Err(Suspension::from_future(async move {
+        let value = match ctx {
+          Some(ctx) => abortable(&ctx.signal(), deferred_server_data(&ctx, &data_url))
+            .await
+            .ok()
+            .flatten(),
+          None => match deferred_embedded_data(&data_url).await {
+            Some(value) => Some(value),
+            None => fetch_data(&data_url).await.ok(),
+          },
+        };
+        data.set(Some((data_url, value.and_then(deserialize::<T>))));
+      }))
+    }
+  }
+}
@@ -0,0 +1,54 @@
+use std::fmt;
+
+use aleph_wasm::abort::Aborted;
+use js_sys::{Error, Reflect};
+use wasm_bindgen::prelude::*;
+
+/// The errors returned by the SSR exports.
+///
+/// They are thrown to Aleph's renderer as a JS `Error` with a `status` property, so the server
+/// responds with the matching error page and calls the `onError` handler with the `"ssr"` cause.
+#[derive(Debug)]
+pub enum SSRError {
+  /// The request can't be handled by the app, e.g. a malformed URL.
+  BadRequest(String),
+  /// The app failed to render.
+  Internal(String),
+  /// The request was aborted before the app was rendered, e.g. the client disconnected.
+  Aborted,
+}
+
+impl SSRError {
+  pub fn status(&self) -> u16 {
+    match self {
+      SSRError::BadRequest(_) => 400,
+      SSRError::Internal(_) => 500,
+      // the non-standard "Client Closed Request" status
+      SSRError::Aborted => 499,
+    }
+  }
+}
+
+impl fmt::Display for SSRError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      SSRError::BadRequest(message) => write!(f, "Bad Request: {}", message),
+      SSRError::Internal(message) => write!(f, "Internal Error: {}", message),
+      SSRError::Aborted => write!(f, "Aborted"),
+    }
+  }
+}
+
+impl From<Aborted> for SSRError {
+  fn from(_: Aborted) -> Self {
+    SSRError::Aborted
+  }
+}
+
+impl From<SSRError> for JsValue {
+  fn from(err: SSRError) -> Self {
+    let error = Error::new(&err.to_string());
+    _ = Reflect::set(&error, &"status".into(), &err.status().into());
+    error.into()
+  }
+}
@@ -0,0 +1,150 @@
+use wasm_bindgen::prelude::*;
+use web_sys::{Document, Element};
+use yew::prelude::*;
+
+use super::context::{use_ssr_context, SSRContext};
+
+/// A `<title>`, `<meta>` or `<link>` element to render into the document head.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HeadTag {
+  name: &'static str,
+  attrs: Vec<(String, String)>,
+  content: Option<String>,
+}
+
+impl HeadTag {
+  /// Creates a `<title>` element.
+  pub fn title(title: impl Into<String>) -> Self {
+    Self {
+      name: "title",
+      attrs: vec![],
+      content: Some(title.into()),
+    }
+  }
+
+  /// Creates a `<meta name="..." content="...">` element.
+  pub fn meta(name: impl Into<String>, content: impl Into<String>) -> Self {
+    Self::element("meta").attr("name", name).attr("content", content)
+  }
+
+  /// Creates a `<meta property="..." content="...">` element, e.g. for Open Graph tags.
+  pub fn property(property: impl Into<String>, content: impl Into<String>) -> Self {
+    Self::element("meta").attr("property", property).attr("content", content)
+  }
+
+  /// Creates a `<link rel="..." href="...">` element.
+  pub fn link(rel: impl Into<String>, href: impl Into<String>) -> Self {
+    Self::element("link").attr("rel", rel).attr("href", href)
+  }
+
+  /// Adds an attribute to the element.
+  pub fn attr(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+    self.attrs.push((key.into(), value.into()));
+    self
+  }
+
+  fn element(name: &'static str) -> Self {
+    Self {
+      name,
+      attrs: vec![],
+      content: None,
+    }
+  }
+
+  /// Renders the element as html, marked with the `ssr` attribute so the client can replace it.
+  fn to_html(&self) -> String {
+    let attrs: String = self
+      .attrs
+      .iter()
+      .map(|(key, value)| format!(" {}=\"{}\"", key, escape(value)))
+      .collect();
+    match &self.content {
+      Some(content) => format!("<{0}{1} ssr>{2}</{0}>", self.name, attrs, escape(content)),
+      None => format!("<{}{} ssr>", self.name, attrs),
+    }
+  }
+
+  fn create_element(&self, document: &Document) -> Result<Element, JsValue> {
+    let el = document.create_element(self.name)?;
+    for (key, value) in &self.attrs {
+      el.set_attribute(key, value)?;
+    }
+    if let Some(content) = &self.content {
+      el.set_text_content(Some(content));
+    }
+    Ok(el)
+  }
+}
+
+fn escape(s: &str) -> String {
+  s.replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+}
+
+fn collect(ctx: &SSRContext, tags: &[HeadTag]) {
+  let collection = ctx.head_collection();
+  for tag in tags {
+    let html = JsValue::from(tag.to_html());
+    // a document has only one title, the innermost component wins
+    let title = match tag.name {
+      "title" => collection.find_index(&mut |v, _, _| matches!(v.as_string(), Some(s) if s.starts_with("<title"))),
+      _ => -1,
+    };
+    if title >= 0 {
+      collection.set(title as u32, html);
+    } else {
+      collection.push(&html);
+    }
+  }
+}
+
+/// Renders the given tags into the document head.
+///
+/// During SSR the tags are pushed into the `headCollection` of Aleph's renderer, in the browser
+/// they replace the server-rendered tags and are removed when the component is unmounted.
+#[hook]
+pub fn use_head(tags: Vec<HeadTag>) {
+  let ctx = use_ssr_context();
+
+  use_memo(
+    move |tags: &Vec<HeadTag>| {
+      if let Some(ctx) = ctx {
+        collect(&ctx, tags);
+      }
+    },
+    tags.clone(),
+  );
+
+  use_effect_with_deps(
+    |tags: &Vec<HeadTag>| {
+      let mut inserted: Vec<Element> = vec![];
+      let document = web_sys::window().and_then(|window| window.document());
+      if let Some((document, head)) = document.and_then(|document| document.head().map(|head| (document, head))) {
+        // remove the tags rendered on the server
+        if let Ok(nodes) = head.query_selector_all("[ssr]") {
+          for i in 0..nodes.length() {
+            if let Some(node) = nodes.item(i) {
+              _ = head.remove_child(&node);
+            }
+          }
+        }
+        for tag in tags {
+          if tag.name == "title" {
+            if let Ok(Some(el)) = head.query_selector("title") {
+              el.remove();
+            }
+          }
+          if let Ok(el) = tag.create_element(&document) {
+            if head.append_child(&el).is_ok() {
+              inserted.push(el);
+            }
+          }
+        }
+      }
+      move || inserted.into_iter().for_each(|el| el.remove())
+    },
+    tags,
+  );
+}
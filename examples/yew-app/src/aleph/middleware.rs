@@ -0,0 +1,44 @@
+use js_sys::{Object, Promise, Reflect};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::future_to_promise;
+use web_sys::{Headers, Request, Response, ResponseInit};
+
+use super::api::{Context, HandlerFuture};
+
+/// A middleware of Aleph's server, see the `Middleware` interface of `server/types.ts`.
+///
+/// Call `ctx.next()` to run the next middleware or the route handler and get its response, or
+/// return a response to stop the chain. The errors are passed to the `onError` handler of the
+/// server with the `"middleware"` cause.
+pub trait Middleware: 'static {
+  /// The middleware name.
+  const NAME: &'static str;
+
+  fn fetch(req: Request, ctx: Context) -> HandlerFuture;
+}
+
+/// Returns the middleware as the `{ name, fetch }` object of the `middlewares` option.
+pub fn into_js<M: Middleware>() -> Object {
+  let fetch = Closure::<dyn Fn(Request, Context) -> Promise>::new(|req, ctx| {
+    future_to_promise(async move { M::fetch(req, ctx).await.map(JsValue::from) })
+  });
+  let middleware = Object::new();
+  _ = Reflect::set(&middleware, &"name".into(), &M::NAME.into());
+  _ = Reflect::set(&middleware, &"fetch".into(), &fetch.into_js_value());
+  middleware
+}
+
+/// Sets a header of the response, the response is copied if its headers are immutable, e.g. the
+/// response of a `fetch` call.
+pub fn with_header(res: Response, name: &str, value: &str) -> Result<Response, JsValue> {
+  if res.headers().set(name, value).is_ok() {
+    return Ok(res);
+  }
+  let headers = Headers::new_with_headers(&res.headers())?;
+  headers.set(name, value)?;
+  let init = ResponseInit::new();
+  init.set_status(res.status());
+  init.set_status_text(&res.status_text());
+  init.set_headers(&headers);
+  Response::new_with_opt_readable_stream_and_init(res.body().as_ref(), &init)
+}
@@ -0,0 +1,142 @@
+use js_sys::{Array, Object};
+use percent_encoding::percent_decode_str;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+use std::str::FromStr;
+use yew::prelude::*;
+
+/// The params of the matched route, e.g. `{ "list_id": "1" }` of `/todos/:list_id`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Params(Rc<HashMap<String, String>>);
+
+impl Params {
+  /// Parses the param `name` into a typed value.
+  pub fn get<T: FromStr>(&self, name: &str) -> Result<T, ParamsError> {
+    let raw = self.0.get(name).ok_or_else(|| ParamsError::Missing(name.to_owned()))?;
+    let value = percent_decode_str(raw)
+      .decode_utf8()
+      .map(String::from)
+      .unwrap_or_else(|_| raw.clone());
+    value.parse().map_err(|_| ParamsError::Invalid {
+      name: name.to_owned(),
+      value,
+    })
+  }
+
+  /// Merges the params of the matched route modules, like `ctx.params` of Aleph's router.
+  pub(crate) fn merge<'a>(all: impl IntoIterator<Item = &'a Params>) -> Self {
+    let mut params = HashMap::new();
+    for p in all {
+      params.extend(p.0.iter().map(|(name, value)| (name.clone(), value.clone())));
+    }
+    Self(Rc::new(params))
+  }
+}
+
+impl From<HashMap<String, String>> for Params {
+  fn from(params: HashMap<String, String>) -> Self {
+    Self(Rc::new(params))
+  }
+}
+
+impl From<Object> for Params {
+  fn from(params: Object) -> Self {
+    let params = Object::entries(&params)
+      .iter()
+      .filter_map(|entry| {
+        let entry = Array::from(&entry);
+        Some((entry.get(0).as_string()?, entry.get(1).as_string()?))
+      })
+      .collect();
+    Self(Rc::new(params))
+  }
+}
+
+/// The error of parsing the route params, the route renders the 404 page.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParamsError {
+  Missing(String),
+  Invalid { name: String, value: String },
+}
+
+impl fmt::Display for ParamsError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ParamsError::Missing(name) => write!(f, "Missing param `{}`", name),
+      ParamsError::Invalid { name, value } => write!(f, "Invalid param `{}`: {}", name, value),
+    }
+  }
+}
+
+/// The typed params of a route.
+pub trait FromParams: Sized {
+  fn from_params(params: &Params) -> Result<Self, ParamsError>;
+}
+
+/// Returns the typed params of the matched route, render the 404 page on error.
+#[hook]
+pub fn use_params<T>() -> Result<T, ParamsError>
+where
+  T: FromParams,
+{
+  let params = use_context::<Params>().unwrap_or_default();
+  T::from_params(&params)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn params(pairs: &[(&str, &str)]) -> Params {
+    Params::from(
+      pairs
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect::<HashMap<_, _>>(),
+    )
+  }
+
+  #[test]
+  fn get() {
+    let params = params(&[("list_id", "2"), ("name", "groceries")]);
+    assert_eq!(params.get::<u32>("list_id"), Ok(2));
+    assert_eq!(params.get::<String>("name"), Ok("groceries".to_owned()));
+    assert_eq!(
+      params.get::<u32>("post_id"),
+      Err(ParamsError::Missing("post_id".to_owned()))
+    );
+    assert_eq!(
+      params.get::<u32>("name"),
+      Err(ParamsError::Invalid {
+        name: "name".to_owned(),
+        value: "groceries".to_owned(),
+      })
+    );
+  }
+
+  #[test]
+  fn percent_decoding() {
+    let params = params(&[
+      ("name", "caf%C3%A9%20au+lait"),
+      ("path", "docs/a%2Fb"),
+      ("bad", "%C3%28"),
+    ]);
+    assert_eq!(params.get::<String>("name"), Ok("café au+lait".to_owned()));
+    assert_eq!(params.get::<String>("path"), Ok("docs/a/b".to_owned()));
+    assert_eq!(params.get::<String>("bad"), Ok("%C3%28".to_owned()));
+    assert_eq!(
+      params.get::<u32>("bad"),
+      Err(ParamsError::Invalid {
+        name: "bad".to_owned(),
+        value: "%C3%28".to_owned(),
+      })
+    );
+  }
+
+  #[test]
+  fn merge() {
+    let merged = Params::merge(&[params(&[("uid", "1")]), params(&[("uid", "1"), ("post_id", "2")])]);
+    assert_eq!(merged, params(&[("uid", "1"), ("post_id", "2")]));
+  }
+}
@@ -0,0 +1,141 @@
+use std::fmt;
+use std::rc::Rc;
+use std::str::FromStr;
+use url::form_urlencoded;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+/// The query of the location, in order and with the repeated keys, e.g. `?tag=a&tag=b`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Query(Rc<Vec<(String, String)>>);
+
+impl Query {
+  /// Parses the query string of the location, with or without the leading `?`.
+  pub fn parse(query_str: &str) -> Self {
+    let query_str = query_str.strip_prefix('?').unwrap_or(query_str);
+    Self(Rc::new(form_urlencoded::parse(query_str.as_bytes()).into_owned().collect()))
+  }
+
+  /// Returns the pairs of the query in order.
+  pub fn pairs(&self) -> &[(String, String)] {
+    &self.0
+  }
+
+  /// Parses the first value of `key` into a typed value, `None` if the key is absent.
+  pub fn get<T: FromStr>(&self, key: &str) -> Result<Option<T>, QueryError> {
+    self.get_all(key).map(|values| values.into_iter().next())
+  }
+
+  /// Parses all the values of `key` in order, e.g. `["a", "b"]` of `?tag=a&tag=b`.
+  pub fn get_all<T: FromStr>(&self, key: &str) -> Result<Vec<T>, QueryError> {
+    self
+      .0
+      .iter()
+      .filter(|(k, _)| k == key)
+      .map(|(_, value)| {
+        value.parse().map_err(|_| QueryError {
+          key: key.to_owned(),
+          value: value.clone(),
+        })
+      })
+      .collect()
+  }
+}
+
+/// The error of parsing a query value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryError {
+  pub key: String,
+  pub value: String,
+}
+
+impl fmt::Display for QueryError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "Invalid query `{}`: {}", self.key, self.value)
+  }
+}
+
+/// The typed query of a route.
+pub trait FromQuery: Sized {
+  fn from_query(query: &Query) -> Result<Self, QueryError>;
+}
+
+/// The fragment of the request url, stored in the state of the SSR history since the memory
+/// history has no fragment.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct SSRFragment(pub String);
+
+/// Returns the typed query of the location.
+#[hook]
+pub fn use_query<T>() -> Result<T, QueryError>
+where
+  T: FromQuery,
+{
+  let query_str = use_location().map(|location| location.query_str().to_owned()).unwrap_or_default();
+  let query = use_memo(|query_str| Query::parse(query_str), query_str);
+  T::from_query(&query)
+}
+
+/// Returns the fragment of the location without the leading `#`.
+///
+/// Browsers don't send the fragment to the server, so it's empty on the server unless the
+/// request url was created with one, e.g. by a redirect of the app.
+#[hook]
+pub fn use_fragment() -> String {
+  let location = use_location();
+  location
+    .map(|location| match location.state::<SSRFragment>() {
+      Some(fragment) => fragment.0.clone(),
+      None => location.hash().trim_start_matches('#').to_owned(),
+    })
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse() {
+    let query = Query::parse("?tag=a&page=2");
+    assert_eq!(
+      query.pairs(),
+      [("tag".to_owned(), "a".to_owned()), ("page".to_owned(), "2".to_owned())]
+    );
+    assert_eq!(Query::parse("tag=a&page=2"), query);
+    assert!(Query::parse("").pairs().is_empty());
+    assert!(Query::parse("?").pairs().is_empty());
+  }
+
+  #[test]
+  fn repeated_keys() {
+    let query = Query::parse("?tag=a&page=2&tag=b");
+    assert_eq!(query.get_all::<String>("tag"), Ok(vec!["a".to_owned(), "b".to_owned()]));
+    assert_eq!(query.get::<String>("tag"), Ok(Some("a".to_owned())));
+    assert_eq!(query.get_all::<String>("sort"), Ok(vec![]));
+    assert_eq!(query.get::<String>("sort"), Ok(None));
+  }
+
+  #[test]
+  fn percent_decoding() {
+    let query = Query::parse("?q=caf%C3%A9+au%20lait&a%26b=1");
+    assert_eq!(query.get::<String>("q"), Ok(Some("café au lait".to_owned())));
+    assert_eq!(query.get::<u32>("a&b"), Ok(Some(1)));
+  }
+
+  #[test]
+  fn invalid_value() {
+    let query = Query::parse("?page=1&page=two");
+    assert_eq!(
+      query.get_all::<u32>("page"),
+      Err(QueryError {
+        key: "page".to_owned(),
+        value: "two".to_owned(),
+      })
+    );
+    assert_eq!(
+      query.get::<u32>("page").map_err(|err| err.to_string()),
+      Err("Invalid query `page`: two".to_owned())
+    );
+  }
+}
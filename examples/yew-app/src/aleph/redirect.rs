@@ -0,0 +1,103 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use web_sys::{Event, Headers, Response, ResponseInit};
+use yew::prelude::*;
+
+use super::context::use_ssr_context;
+use super::router::{base_path, with_base};
+
+/// The redirect requested by the routes while the app is rendered on the server.
+#[derive(Clone, Debug, Default)]
+pub struct SSRRedirect(Rc<RefCell<Option<(String, u16)>>>);
+
+impl PartialEq for SSRRedirect {
+  fn eq(&self, other: &Self) -> bool {
+    Rc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+impl SSRRedirect {
+  /// Returns the redirect response with the `Location` header, if a route requested one.
+  ///
+  /// The SSR exports throw it to Aleph's server, which responds with it as-is.
+  pub fn response(&self) -> Result<Option<Response>, JsValue> {
+    let (location, status) = match &*self.0.borrow() {
+      Some(redirect) => redirect.clone(),
+      None => return Ok(None),
+    };
+    let headers = Headers::new()?;
+    headers.set("Location", &location)?;
+    let init = ResponseInit::new();
+    init.set_status(status);
+    init.set_headers(&headers);
+    Response::new_with_opt_str_and_init(None, &init).map(Some)
+  }
+}
+
+#[wasm_bindgen(raw_module = "aleph/framework/core/redirect.ts")]
+extern "C" {
+  /// The `redirect` function of Aleph's client, it pushes the url to the history or loads the
+  /// external urls.
+  #[wasm_bindgen(js_name = redirect)]
+  fn aleph_redirect(href: &str, replace: bool);
+}
+
+/// Navigates to `href` in the browser with Aleph's `redirect`, the paths of the app are under the
+/// base path of the router.
+fn redirect(href: &str, replace: bool) {
+  let window = match web_sys::window() {
+    Some(window) => window,
+    None => return,
+  };
+  let current = window.location().href().unwrap_or_default();
+  aleph_redirect(&with_base(href, &base_path(None)), replace);
+  // the `BrowserRouter` of the app follows the history on `popstate`
+  if window.location().href().unwrap_or_default() != current {
+    if let Ok(event) = Event::new("popstate") {
+      _ = window.dispatch_event(&event);
+    }
+  }
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct RedirectProps {
+  /// The url to redirect to, either a path of the app or an external url. The paths of the app
+  /// are under the base path of the router.
+  pub to: String,
+  /// Replaces the current history entry instead of pushing a new one.
+  #[prop_or_default]
+  pub replace: bool,
+  /// The status code of the redirect response on the server.
+  #[prop_or(302)]
+  pub status: u16,
+}
+
+/// Redirects to another url when rendered, e.g. sending an unauthenticated user to `/login`.
+///
+/// On the server the response becomes a redirect with the `Location` header, in the browser the
+/// url is pushed to the history. When streaming, a redirect rendered below the first suspended
+/// component comes after the response is sent and can't change it anymore, it's applied after
+/// the hydration instead.
+#[function_component]
+pub fn Redirect(props: &RedirectProps) -> Html {
+  let ctx = use_ssr_context();
+  let ssr_redirect = use_context::<SSRRedirect>();
+
+  if let (Some(ctx), Some(ssr_redirect)) = (ctx, ssr_redirect) {
+    ssr_redirect
+      .0
+      .borrow_mut()
+      .get_or_insert_with(|| (with_base(&props.to, &ctx.base_path()), props.status));
+  }
+
+  use_effect_with_deps(
+    |(to, replace)| {
+      redirect(to, *replace);
+      || ()
+    },
+    (props.to.clone(), props.replace),
+  );
+
+  Html::default()
+}
@@ -0,0 +1,512 @@
+use js_sys::{Array, Object, Reflect, JSON};
+use serde::{Deserialize, Serialize};
+use serde_wasm_bindgen::Serializer;
+use std::collections::HashMap;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::Url;
+use yew::prelude::*;
+use yew_router::history::Location;
+use yew_router::prelude::*;
+
+use super::context::{use_ssr_context, SSRContext};
+use super::params::Params;
+
+pub use aleph_wasm::base::{strip_base, with_base};
+
+/// The `RouteMeta` of Aleph's router, see `framework/core/router.ts`.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct RouteMeta {
+  /// The routes defined by code have no module file.
+  #[serde(default)]
+  filename: String,
+  pattern: RoutePattern,
+  /// Set by Aleph's router if other routes are nested in the route.
+  #[serde(default, skip_serializing)]
+  nesting: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RoutePattern {
+  pathname: String,
+}
+
+impl RouteMeta {
+  /// Creates the meta of a route defined by code.
+  pub(crate) fn new(pathname: String) -> Self {
+    Self {
+      filename: String::new(),
+      pattern: RoutePattern { pathname },
+      nesting: false,
+    }
+  }
+}
+
+/// Converts a Yew route path to the pattern of Aleph's router, e.g. `/blog/*path` to
+/// `/blog/:path+`.
+fn to_pattern(path: &str) -> String {
+  path
+    .split('/')
+    .map(|segment| match segment.strip_prefix('*') {
+      Some("") => ":path+".to_owned(),
+      Some(name) => format!(":{}+", name),
+      None => segment.to_owned(),
+    })
+    .collect::<Vec<_>>()
+    .join("/")
+}
+
+/// Returns the route table of the `Routable` enum as Aleph's `RouteMeta` list, pass it to the
+/// `router.routes` option of the server.
+///
+/// The `#[not_found]` route becomes the `/_404` route, and the `/_app` route is added for the
+/// root component of the app. A route with nested routes gets an `/index` route as well, which
+/// is matched by its own path like the `index.ts` module of a directory in `routes`.
+pub fn route_metas<R: Routable>() -> Result<JsValue, JsValue> {
+  let metas = route_pathnames::<R>()
+    .into_iter()
+    .map(RouteMeta::new)
+    .collect::<Vec<_>>();
+  Ok(metas.serialize(&Serializer::json_compatible())?)
+}
+
+/// Returns the pathnames of the route table of [`route_metas`].
+fn route_pathnames<R: Routable>() -> Vec<String> {
+  let not_found = R::not_found_route().map(|route| route.to_path());
+  let mut pathnames = vec!["/_app".to_owned()];
+  for path in R::routes() {
+    if not_found.as_deref() == Some(path) {
+      pathnames.push("/_404".to_owned());
+    } else {
+      pathnames.push(to_pattern(path));
+    }
+  }
+  let index_pathnames = pathnames
+    .iter()
+    .filter(|pathname| pathname.as_str() != "/_app" && pathname.as_str() != "/")
+    .filter(|pathname| {
+      let prefix = format!("{}/", pathname);
+      pathnames.iter().any(|p| p.starts_with(&prefix))
+    })
+    .map(|pathname| format!("{}/index", pathname))
+    .collect::<Vec<_>>();
+  pathnames.into_iter().chain(index_pathnames).collect()
+}
+
+/// Provides the paths of the parameterised routes for Aleph's static site generation, the
+/// routes without params are pre-rendered from the route table.
+pub trait StaticPaths: Routable {
+  fn static_paths() -> Vec<Self>;
+}
+
+/// Returns the paths of [`StaticPaths`] for the `build.ssg.getStaticPaths` option of the server.
+pub fn static_paths<R: StaticPaths>() -> Array {
+  R::static_paths()
+    .iter()
+    .map(|route| JsValue::from(route.to_path()))
+    .collect()
+}
+
+/// A route module matched by Aleph's router, see the `RouteModule` of `server/types.ts`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RouteMatch {
+  /// The `pathname + search` of the module, the key of its data.
+  pub url: String,
+  pub params: Params,
+}
+
+/// The route modules matched for the current location, from the `_app` shell to the deepest
+/// nested route.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RouteMatches(Rc<Vec<RouteMatch>>);
+
+/// The `router-manifest` script embedded by Aleph's renderer.
+#[derive(Default, Deserialize)]
+struct RouterManifest {
+  #[serde(default)]
+  routes: Vec<RouteMeta>,
+  #[serde(default, rename = "basePath")]
+  base_path: String,
+}
+
+thread_local! {
+  static MANIFEST: Rc<RouterManifest> = Rc::new(load_manifest());
+}
+
+fn load_manifest() -> RouterManifest {
+  let json = web_sys::window()
+    .and_then(|window| window.document())
+    .and_then(|document| document.get_element_by_id("router-manifest"))
+    .and_then(|el| el.text_content());
+  json
+    .and_then(|json| JSON::parse(&json).ok())
+    .and_then(|manifest| serde_wasm_bindgen::from_value(manifest).ok())
+    .unwrap_or_default()
+}
+
+/// Returns the `router.basePath` option of the server, the app is served under it in the
+/// browser. It's an empty string if the app is served at the root.
+pub fn base_path(ctx: Option<&SSRContext>) -> String {
+  match ctx {
+    Some(ctx) => ctx.base_path(),
+    None => MANIFEST.with(|manifest| manifest.base_path.clone()),
+  }
+}
+
+/// Returns the search of the location, or an empty string if there is no query.
+fn search(location: &Location) -> &str {
+  match location.query_str() {
+    "" | "?" => "",
+    query => query,
+  }
+}
+
+fn segments(path: &str) -> Vec<&str> {
+  path.split('/').filter(|segment| !segment.is_empty()).collect()
+}
+
+/// Matches the pathname against a pattern of Aleph's router, e.g. `/todos/:list_id` or
+/// `/blog/:path+`.
+fn match_pattern(pattern: &str, pathname: &str) -> Option<HashMap<String, String>> {
+  let pattern = segments(pattern);
+  let path = segments(pathname);
+  let mut params = HashMap::new();
+  for (i, segment) in pattern.iter().enumerate() {
+    match segment.strip_prefix(':') {
+      Some(name) if name.ends_with('+') || name.ends_with('*') => {
+        let rest = path.get(i..).unwrap_or_default();
+        if rest.is_empty() && name.ends_with('+') {
+          return None;
+        }
+        if !rest.is_empty() {
+          params.insert(name[..name.len() - 1].to_owned(), rest.join("/"));
+        }
+        return Some(params);
+      }
+      Some(name) => {
+        params.insert(name.to_owned(), (*path.get(i)?).to_owned());
+      }
+      None if path.get(i) == Some(segment) => {}
+      None => return None,
+    }
+  }
+  if pattern.len() == path.len() {
+    Some(params)
+  } else {
+    None
+  }
+}
+
+/// Matches the location against the routes of the manifest, with the nesting semantics of
+/// `matchRoutes` in `framework/core/router.ts`.
+fn match_routes(routes: &[RouteMeta], pathname: &str, search: &str) -> Vec<RouteMatch> {
+  let pathname = match pathname.trim_end_matches('/') {
+    "" => "/",
+    pathname => pathname,
+  };
+  let exec = |meta: &RouteMeta, pathname: &str| {
+    match_pattern(&meta.pattern.pathname, pathname).map(|params| RouteMatch {
+      url: format!("{}{}", pathname, search),
+      params: Params::from(params),
+    })
+  };
+  let is_special = |meta: &RouteMeta| meta.pattern.pathname == "/_app" || meta.pattern.pathname == "/_404";
+  let index = format!("{}/index", pathname);
+  let mut matches = Vec::new();
+
+  // find the direct match, or the index route if no direct match
+  let direct = routes
+    .iter()
+    .filter(|meta| !is_special(meta))
+    .find_map(|meta| Some((exec(meta, pathname)?, meta)))
+    .or_else(|| {
+      routes
+        .iter()
+        .filter(|meta| meta.pattern.pathname.ends_with("/index"))
+        .find_map(|meta| Some((exec(meta, &index)?, meta)))
+    });
+  if let Some((direct_match, direct)) = direct {
+    // lookup the nesting parents
+    let parts = segments(pathname);
+    for i in 1..parts.len() {
+      let parent = format!("/{}", parts[..i].join("/"));
+      let parent_match = routes
+        .iter()
+        .filter(|meta| meta.nesting && meta.pattern.pathname != "/_app")
+        .filter(|meta| direct.pattern.pathname.starts_with(&format!("{}/", meta.pattern.pathname)))
+        .find_map(|meta| Some((exec(meta, &parent)?, meta)));
+      matches.extend(parent_match);
+    }
+    matches.push((direct_match, direct));
+    if direct.nesting {
+      let index_pattern = format!("{}/index", direct.pattern.pathname);
+      let index_match = routes
+        .iter()
+        .filter(|meta| meta.pattern.pathname == index_pattern)
+        .find_map(|meta| Some((exec(meta, &index)?, meta)));
+      matches.extend(index_match);
+    }
+  }
+
+  let mut matches = matches
+    .into_iter()
+    .map(|(route_match, meta)| (route_match, meta.nesting))
+    .collect::<Vec<_>>();
+  let static_match = |pathname: &str| RouteMatch {
+    url: format!("{}{}", pathname, search),
+    params: Params::default(),
+  };
+  if routes.iter().any(|meta| meta.pattern.pathname == "/_404") && matches.iter().all(|(_, nesting)| *nesting) {
+    matches.push((static_match("/_404"), false));
+  }
+  if routes.iter().any(|meta| meta.pattern.pathname == "/_app") && !matches.is_empty() {
+    matches.insert(0, (static_match("/_app"), true));
+  }
+  matches.into_iter().map(|(route_match, _)| route_match).collect()
+}
+
+/// Returns the route modules that Aleph's router matched for the request.
+fn server_matches(ctx: &SSRContext) -> Vec<RouteMatch> {
+  ctx
+    .modules()
+    .iter()
+    .map(|module| {
+      let get = |key: &str| Reflect::get(&module, &JsValue::from_str(key)).unwrap_or(JsValue::UNDEFINED);
+      let url: Url = get("url").unchecked_into();
+      RouteMatch {
+        url: format!("{}{}", url.pathname(), url.search()),
+        params: get("params").dyn_into::<Object>().map(Params::from).unwrap_or_default(),
+      }
+    })
+    .collect()
+}
+
+#[derive(Properties, PartialEq)]
+pub struct RouteProviderProps {
+  #[prop_or_default]
+  pub children: Children,
+}
+
+/// Provides the matched route modules to the [`Outlet`]s and the data hooks, and their params
+/// to `use_params`.
+///
+/// On the server the modules are the `ctx.modules` matched by Aleph's router, so the data
+/// loaders and the components agree. In the browser the location is matched against the
+/// `router-manifest` with the same nesting semantics.
+#[function_component]
+pub fn RouteProvider(props: &RouteProviderProps) -> Html {
+  let ctx = use_ssr_context();
+  let (pathname, search) = use_location()
+    .map(|location| (location.path().to_owned(), search(&location).to_owned()))
+    .unwrap_or_default();
+  let matches = use_memo(
+    |(ctx, pathname, search)| {
+      RouteMatches(Rc::new(match ctx {
+        Some(ctx) => server_matches(ctx),
+        None => MANIFEST.with(|manifest| {
+          // Aleph's router matches the paths without the base path
+          match_routes(&manifest.routes, strip_base(pathname, &manifest.base_path), search)
+        }),
+      }))
+    },
+    (ctx, pathname, search),
+  );
+  let params = use_memo(
+    |matches| Params::merge(matches.0.iter().map(|route_match| &route_match.params)),
+    (*matches).clone(),
+  );
+
+  html! {
+    <ContextProvider<RouteMatches> context={(*matches).clone()}>
+      <ContextProvider<Params> context={(*params).clone()}>
+        {props.children.clone()}
+      </ContextProvider<Params>>
+    </ContextProvider<RouteMatches>>
+  }
+}
+
+/// The depth of the route level that a component renders, `0` is the `_app` shell.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct RouteDepth(usize);
+
+#[derive(Properties, PartialEq)]
+pub struct OutletProps {
+  #[prop_or_default]
+  pub children: Children,
+}
+
+/// Renders the child route of a layout, a level deeper in the matched route modules.
+///
+/// The components must nest like the routes do: the `_app` shell renders the route in an
+/// `<Outlet>`, and a layout route renders its nested routes in another one.
+#[function_component]
+pub fn Outlet(props: &OutletProps) -> Html {
+  let depth = use_context::<RouteDepth>().unwrap_or_default();
+
+  html! {
+    <ContextProvider<RouteDepth> context={RouteDepth(depth.0 + 1)}>
+      {props.children.clone()}
+    </ContextProvider<RouteDepth>>
+  }
+}
+
+/// Returns the route module of the level that the component renders.
+#[hook]
+pub fn use_route_match() -> Option<RouteMatch> {
+  let depth = use_context::<RouteDepth>().unwrap_or_default();
+  let matches = use_context::<RouteMatches>().unwrap_or_default();
+  matches.0.get(depth.0).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[derive(Clone, PartialEq, Routable)]
+  enum TestRoute {
+    #[at("/")]
+    Home,
+    #[at("/todos")]
+    Todos,
+    #[at("/todos/new")]
+    NewTodo,
+    #[at("/todos/:list_id")]
+    TodoList { list_id: String },
+    #[at("/docs/*path")]
+    Docs { path: String },
+    #[at("/404")]
+    #[not_found]
+    NotFound,
+  }
+
+  /// The routes of [`TestRoute`] in the order and with the nesting of Aleph's router.
+  fn routes() -> Vec<RouteMeta> {
+    [
+      "/_app",
+      "/_404",
+      "/",
+      "/todos",
+      "/todos/index",
+      "/todos/new",
+      "/todos/:list_id",
+      "/docs/:path+",
+    ]
+    .into_iter()
+    .map(|pathname| RouteMeta {
+      nesting: pathname == "/_app" || pathname == "/todos",
+      ..RouteMeta::new(pathname.to_owned())
+    })
+    .collect()
+  }
+
+  fn route_match(url: &str, params: &[(&str, &str)]) -> RouteMatch {
+    let params = params
+      .iter()
+      .map(|(name, value)| (name.to_string(), value.to_string()))
+      .collect::<HashMap<_, _>>();
+    RouteMatch {
+      url: url.to_owned(),
+      params: Params::from(params),
+    }
+  }
+
+  #[test]
+  fn pattern() {
+    assert_eq!(to_pattern("/"), "/");
+    assert_eq!(to_pattern("/todos/:list_id"), "/todos/:list_id");
+    assert_eq!(to_pattern("/docs/*path"), "/docs/:path+");
+    assert_eq!(to_pattern("/docs/*"), "/docs/:path+");
+  }
+
+  #[test]
+  fn pathnames() {
+    assert_eq!(
+      route_pathnames::<TestRoute>(),
+      [
+        "/_app",
+        "/",
+        "/todos",
+        "/todos/new",
+        "/todos/:list_id",
+        "/docs/:path+",
+        "/_404",
+        "/todos/index"
+      ]
+    );
+  }
+
+  #[test]
+  fn match_params() {
+    assert_eq!(match_pattern("/", "/"), Some(HashMap::new()));
+    assert_eq!(match_pattern("/todos", "/todos/2"), None);
+    let params = match_pattern("/users/:uid/posts/:post_id", "/users/1/posts/2").unwrap();
+    assert_eq!(params.get("uid").map(String::as_str), Some("1"));
+    assert_eq!(params.get("post_id").map(String::as_str), Some("2"));
+  }
+
+  #[test]
+  fn match_catch_all() {
+    let params = match_pattern("/docs/:path+", "/docs/guide/routing").unwrap();
+    assert_eq!(params.get("path").map(String::as_str), Some("guide/routing"));
+    let params = match_pattern("/docs/:path+", "/docs/guide").unwrap();
+    assert_eq!(params.get("path").map(String::as_str), Some("guide"));
+    assert_eq!(match_pattern("/docs/:path+", "/docs"), None);
+    assert_eq!(match_pattern("/docs/:path*", "/docs"), Some(HashMap::new()));
+  }
+
+  #[test]
+  fn match_index() {
+    assert_eq!(
+      match_routes(&routes(), "/todos/", "?tag=a"),
+      [
+        route_match("/_app?tag=a", &[]),
+        route_match("/todos?tag=a", &[]),
+        route_match("/todos/index?tag=a", &[]),
+      ]
+    );
+    let routes = routes()
+      .into_iter()
+      .filter(|meta| meta.pattern.pathname != "/todos")
+      .collect::<Vec<_>>();
+    assert_eq!(
+      match_routes(&routes, "/todos", ""),
+      [route_match("/_app", &[]), route_match("/todos/index", &[])]
+    );
+  }
+
+  #[test]
+  fn match_static_before_dynamic() {
+    assert_eq!(
+      match_routes(&routes(), "/todos/new", ""),
+      [
+        route_match("/_app", &[]),
+        route_match("/todos", &[]),
+        route_match("/todos/new", &[])
+      ]
+    );
+    assert_eq!(
+      match_routes(&routes(), "/todos/2", ""),
+      [
+        route_match("/_app", &[]),
+        route_match("/todos", &[]),
+        route_match("/todos/2", &[("list_id", "2")]),
+      ]
+    );
+  }
+
+  #[test]
+  fn match_not_found() {
+    assert_eq!(
+      match_routes(&routes(), "/docs/guide/routing", ""),
+      [
+        route_match("/_app", &[]),
+        route_match("/docs/guide/routing", &[("path", "guide/routing")])
+      ]
+    );
+    assert_eq!(
+      match_routes(&routes(), "/docs", ""),
+      [route_match("/_app", &[]), route_match("/_404", &[])]
+    );
+  }
+}
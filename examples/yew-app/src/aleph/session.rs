@@ -0,0 +1,94 @@
+use aleph_wasm::session::SESSION_DATA_ID;
+use js_sys::{Object, JSON};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_wasm_bindgen::Serializer;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use yew::prelude::*;
+use yew::suspense::{Suspension, SuspensionResult};
+
+use super::abort::abortable;
+use super::context::use_ssr_context;
+
+pub use aleph_wasm::session::session_store;
+
+/// The session values read by the components while the app is rendered on the server.
+///
+/// They are embedded at the end of the body rather than in the head, since a streamed head is sent
+/// before the components reading the session are resumed.
+#[derive(Clone, Debug, Default)]
+pub struct SSRSession(Rc<RefCell<Option<Object>>>);
+
+impl PartialEq for SSRSession {
+  fn eq(&self, other: &Self) -> bool {
+    Rc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+impl SSRSession {
+  /// Merges the value with the values read by other components.
+  fn merge<T: Serialize>(&self, value: &T) {
+    if let Ok(value) = value.serialize(&Serializer::json_compatible()) {
+      let mut data = self.0.borrow_mut();
+      Object::assign(data.get_or_insert_with(Object::new), value.unchecked_ref());
+    }
+  }
+
+  /// Returns the script that embeds the session values, or an empty string if no component read
+  /// the session.
+  pub fn script(&self) -> String {
+    let json = match &*self.0.borrow() {
+      Some(data) => JSON::stringify(data).map(String::from).unwrap_or_default(),
+      None => return String::new(),
+    };
+    format!(
+      r#"<script type="application/json" id="{}">{}</script>"#,
+      SESSION_DATA_ID,
+      json.replace('<', "\\u003c")
+    )
+  }
+}
+
+/// Returns the session value embedded by the server.
+fn embedded_session<T: DeserializeOwned>() -> Option<T> {
+  let el = web_sys::window()?.document()?.get_element_by_id(SESSION_DATA_ID)?;
+  let store = JSON::parse(&el.text_content()?).ok()?;
+  serde_wasm_bindgen::from_value(store).ok()
+}
+
+/// Returns the session store of the request, e.g. the logged-in user.
+///
+/// The component suspends on the server until the session is loaded, the value is embedded in
+/// the html so the browser reads it without another request.
+#[hook]
+pub fn use_session<T>() -> SuspensionResult<Option<Rc<T>>>
+where
+  T: Serialize + DeserializeOwned + 'static,
+{
+  let ctx = use_ssr_context();
+  let ssr_session = use_context::<SSRSession>();
+  let store = {
+    let is_server = ctx.is_some();
+    use_state(move || if is_server { None } else { Some(embedded_session::<T>().map(Rc::new)) })
+  };
+
+  match (&*store, ctx) {
+    (Some(value), _) => Ok(value.clone()),
+    (None, Some(ctx)) => {
+      let store = store.clone();
+      Err(Suspension::from_future(async move {
+        let value = abortable(&ctx.signal(), session_store::<T>(&ctx))
+          .await
+          .ok()
+          .and_then(|store| store.ok().flatten());
+        if let (Some(value), Some(ssr_session)) = (&value, ssr_session) {
+          ssr_session.merge(value);
+        }
+        store.set(Some(value.map(Rc::new)));
+      }))
+    }
+    (None, None) => Ok(None),
+  }
+}
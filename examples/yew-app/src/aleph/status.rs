@@ -0,0 +1,15 @@
+use yew::prelude::*;
+
+use super::context::use_ssr_context;
+
+/// Sets the HTTP status code of the response when the component is rendered on the server,
+/// e.g. `use_status(404)` in a not-found page.
+///
+/// When streaming, the status must be set above the first suspended component, see
+/// [`into_readable_stream`](super::stream::into_readable_stream).
+#[hook]
+pub fn use_status(code: u16) {
+  if let Some(ctx) = use_ssr_context() {
+    ctx.set_status(code);
+  }
+}
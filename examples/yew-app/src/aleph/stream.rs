@@ -0,0 +1,96 @@
+use futures::stream::{self, Stream, StreamExt};
+use js_sys::{Function, Uint8Array};
+use wasm_bindgen::prelude::*;
+use wasm_streams::ReadableStream;
+
+use super::abort::{abortable, aborted};
+use super::context::{HtmlRewriterElement, SSRContext};
+use super::error::SSRError;
+
+/// The selector of the element that marks the end of the app shell in the streamed html.
+pub const SUSPENSE_MARKER: &str = "template[data-suspense-marker]";
+
+/// Converts the html chunks of Yew's `ServerRenderer::render_stream` into a `ReadableStream`
+/// of bytes that Aleph's renderer can write into the response body.
+///
+/// The first chunk is rendered before the stream is returned, it holds everything Yew renders
+/// until the first component suspends. So the status code and the redirect are applied to the
+/// response only if they are set by components above the first suspension, e.g. outside of the
+/// `<Suspense>` of a page using `use_deferred_route_data`; the ones set below it come after the
+/// response is sent and are ignored. The rendering stops when the request is aborted.
+pub async fn into_readable_stream(
+  ctx: &SSRContext,
+  chunks: impl Stream<Item = String> + 'static,
+) -> Result<ReadableStream, SSRError> {
+  let test = Closure::<dyn Fn(HtmlRewriterElement) -> bool>::new(|el: HtmlRewriterElement| {
+    el.remove();
+    true
+  });
+  ctx.set_suspense_marker(SUSPENSE_MARKER, test.into_js_value().unchecked_ref::<Function>());
+
+  let signal = ctx.signal();
+  let mut chunks = Box::pin(chunks);
+  let shell = abortable(&signal, chunks.next()).await?.unwrap_or_default();
+  let body = stream::once(async move { shell })
+    .chain(chunks)
+    .take_until(aborted(&signal))
+    .map(|chunk| Ok(Uint8Array::from(chunk.as_bytes()).into()));
+  Ok(ReadableStream::from_stream(body))
+}
+
+#[cfg(test)]
+mod tests {
+  use futures::executor::block_on;
+  use futures::StreamExt;
+  use std::cell::{Cell, RefCell};
+  use yew::prelude::*;
+  use yew::suspense::{Suspension, SuspensionHandle};
+
+  thread_local! {
+    static ABOVE: Cell<bool> = const { Cell::new(false) };
+    static BELOW: Cell<bool> = const { Cell::new(false) };
+    static RESUMED: Cell<bool> = const { Cell::new(false) };
+    static HANDLE: RefCell<Option<SuspensionHandle>> = const { RefCell::new(None) };
+  }
+
+  #[function_component]
+  fn Suspended() -> HtmlResult {
+    if !RESUMED.with(Cell::get) {
+      let (suspension, handle) = Suspension::new();
+      HANDLE.with(|h| *h.borrow_mut() = Some(handle));
+      return Err(suspension.into());
+    }
+    BELOW.with(|below| below.set(true));
+    Ok(html! { <p>{"below"}</p> })
+  }
+
+  #[function_component]
+  fn Shell() -> Html {
+    ABOVE.with(|above| above.set(true));
+    html! {
+      <main>
+        <h1>{"above"}</h1>
+        <Suspense fallback={html! { <p>{"loading"}</p> }}>
+          <Suspended />
+        </Suspense>
+      </main>
+    }
+  }
+
+  #[test]
+  fn first_chunk_ends_at_the_first_suspension() {
+    let mut chunks = Box::pin(yew::LocalServerRenderer::<Shell>::new().render_stream());
+    let shell = block_on(chunks.next()).unwrap_or_default();
+    assert!(shell.contains("above"));
+    assert!(!shell.contains("below"));
+    // a status set below the suspension is set after the response is returned
+    assert!(ABOVE.with(Cell::get));
+    assert!(!BELOW.with(Cell::get));
+
+    RESUMED.with(|resumed| resumed.set(true));
+    HANDLE.with(|handle| handle.borrow_mut().take().map(SuspensionHandle::resume));
+    let rest = block_on(chunks.collect::<String>());
+    assert!(rest.contains("below"));
+    assert!(BELOW.with(Cell::get));
+  }
+}
@@ -0,0 +1,20 @@
+pub mod todos;
+pub mod users;
+
+use crate::aleph::api::ApiRouter;
+
+/// The API routes of the app and the data loaders of its pages, served by Aleph's router.
+pub fn router() -> ApiRouter {
+  ApiRouter::new()
+    .data(todos::todo_lists)
+    .data(todos::all_todos)
+    .data(todos::list_todos_data)
+    .service(todos::list_todos)
+    .service(todos::create_todo)
+    .service(todos::update_todo)
+    .service(todos::delete_todo)
+    .service(users::list_users)
+    .service(users::get_user)
+    .service(users::update_user)
+    .service(users::delete_user)
+}
@@ -0,0 +1,249 @@
+//! The `/api/todos` routes, the todos are loaded by the `/todos` pages through the data loaders
+//! of this module during SSR and changed by the browser with its requests.
+
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use wasm_bindgen::JsValue;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{FormData, Request, RequestInit, Response, Url};
+
+use crate::aleph::api::{data, delete, form_data, get, patch, post, ApiError, Context, Json};
+use crate::aleph::params::{FromParams, Params, ParamsError};
+use crate::aleph::router::{base_path, with_base};
+
+/// The list of the todos added on the `/todos` page, which shows the todos of every list.
+pub const DEFAULT_LIST_ID: u32 = 1;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Todo {
+  pub id: u32,
+  pub list_id: u32,
+  pub title: String,
+  pub completed: bool,
+}
+
+impl Todo {
+  /// Validates the title of a new todo, on the server and before the browser sends it.
+  pub fn validate_title(title: Option<String>) -> Result<String, ApiError> {
+    match title {
+      Some(title) if !title.trim().is_empty() => Ok(title.trim().to_owned()),
+      _ => Err(ApiError::bad_request("invalidTitle", "invalid title")),
+    }
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TodoList {
+  pub id: u32,
+  pub title: String,
+}
+
+/// The data of the `/todos` layout.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TodoListsData {
+  pub lists: Vec<TodoList>,
+}
+
+/// The data of the `/todos` index and `/todos/:list_id` pages.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TodosData {
+  pub todos: Vec<Todo>,
+}
+
+struct Store {
+  lists: Vec<TodoList>,
+  todos: Vec<Todo>,
+  next_id: u32,
+}
+
+thread_local! {
+  static STORE: RefCell<Store> = RefCell::new(Store {
+    lists: vec![
+      TodoList { id: 1, title: "Learning".to_owned() },
+      TodoList { id: 2, title: "Reading".to_owned() },
+    ],
+    todos: [
+      (1, "Learn Yew", true),
+      (1, "Build an app with Aleph.js", false),
+      (2, "Read the Rust book", false),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(index, (list_id, title, completed))| Todo {
+      id: index as u32 + 1,
+      list_id,
+      title: title.to_owned(),
+      completed,
+    })
+    .collect(),
+    next_id: 4,
+  });
+}
+
+/// Returns the todos of the list, or of every list if `list_id` is `None`.
+fn todos_of(list_id: Option<u32>) -> Vec<Todo> {
+  STORE.with(|store| {
+    let store = store.borrow();
+    let todos = store.todos.iter();
+    todos.filter(|todo| list_id.is_none() || list_id == Some(todo.list_id)).cloned().collect()
+  })
+}
+
+/// The params of the `/api/todos/:id` routes.
+struct TodoParams {
+  id: u32,
+}
+
+impl FromParams for TodoParams {
+  fn from_params(params: &Params) -> Result<Self, ParamsError> {
+    Ok(Self { id: params.get("id")? })
+  }
+}
+
+fn todo_not_found() -> ApiError {
+  ApiError::not_found("todoNotFound", "todo not found")
+}
+
+/// Returns the id of the route, a malformed id is an unknown todo.
+fn todo_id(ctx: &Context) -> Result<u32, ApiError> {
+  ctx
+    .parse_params::<TodoParams>()
+    .map(|params| params.id)
+    .map_err(|_| todo_not_found())
+}
+
+/// The params of the `/todos/:list_id` page.
+struct ListParams {
+  list_id: u32,
+}
+
+impl FromParams for ListParams {
+  fn from_params(params: &Params) -> Result<Self, ParamsError> {
+    Ok(Self {
+      list_id: params.get("list_id")?,
+    })
+  }
+}
+
+/// Loads the todo lists for the navigation of the `/todos` layout.
+#[data("/todos")]
+pub async fn todo_lists(_req: Request, _ctx: Context) -> Json<TodoListsData> {
+  let lists = STORE.with(|store| store.borrow().lists.clone());
+  Json(TodoListsData { lists })
+}
+
+/// Loads the todos of every list for the `/todos` page, the page shell is streamed before.
+#[data("/todos/index", defer)]
+pub async fn all_todos(_req: Request, _ctx: Context) -> Json<TodosData> {
+  Json(TodosData { todos: todos_of(None) })
+}
+
+/// Loads the todos of the `/todos/:list_id` page, an unknown list has no todos.
+#[data("/todos/:list_id", defer)]
+pub async fn list_todos_data(_req: Request, ctx: Context) -> Json<TodosData> {
+  let todos = match ctx.parse_params::<ListParams>() {
+    Ok(params) => todos_of(Some(params.list_id)),
+    Err(_) => Vec::new(),
+  };
+  Json(TodosData { todos })
+}
+
+/// Returns the todos, of the `?list_id=` list only if it's given.
+#[get("/api/todos")]
+pub async fn list_todos(req: Request, _ctx: Context) -> Result<Json<Vec<Todo>>, ApiError> {
+  let list_id = match Url::new(&req.url())?.search_params().get("list_id") {
+    Some(list_id) => Some(list_id.parse().map_err(|_| ApiError::bad_request("invalidList", "invalid list"))?),
+    None => None,
+  };
+  Ok(Json(todos_of(list_id)))
+}
+
+/// Adds a todo with the `title` to the `list_id` list of the form.
+#[post("/api/todos")]
+pub async fn create_todo(req: Request, _ctx: Context) -> Result<Json<Todo>, ApiError> {
+  let data = form_data(&req).await?;
+  let title = Todo::validate_title(data.get("title").as_string())?;
+  let list_id = data.get("list_id").as_string().and_then(|id| id.parse::<u32>().ok());
+  STORE.with(|store| {
+    let mut store = store.borrow_mut();
+    let list_id = list_id
+      .filter(|id| store.lists.iter().any(|list| list.id == *id))
+      .ok_or_else(|| ApiError::bad_request("invalidList", "invalid list"))?;
+    let todo = Todo {
+      id: store.next_id,
+      list_id,
+      title,
+      completed: false,
+    };
+    store.next_id += 1;
+    store.todos.push(todo.clone());
+    Ok(Json(todo))
+  })
+}
+
+/// Sets the `completed` state of the form, e.g. to toggle the todo.
+#[patch("/api/todos/:id")]
+pub async fn update_todo(req: Request, ctx: Context) -> Result<Json<Todo>, ApiError> {
+  let id = todo_id(&ctx)?;
+  let completed = match form_data(&req).await?.get("completed").as_string().as_deref() {
+    Some("true") => true,
+    Some("false") => false,
+    _ => return Err(ApiError::bad_request("invalidCompleted", "invalid completed")),
+  };
+  STORE.with(|store| {
+    let mut store = store.borrow_mut();
+    let todo = store.todos.iter_mut().find(|todo| todo.id == id).ok_or_else(todo_not_found)?;
+    todo.completed = completed;
+    Ok(Json(todo.clone()))
+  })
+}
+
+#[delete("/api/todos/:id")]
+pub async fn delete_todo(_req: Request, ctx: Context) -> Result<Json<Todo>, ApiError> {
+  let id = todo_id(&ctx)?;
+  STORE.with(|store| {
+    let mut store = store.borrow_mut();
+    let index = store.todos.iter().position(|todo| todo.id == id).ok_or_else(todo_not_found)?;
+    Ok(Json(store.todos.remove(index)))
+  })
+}
+
+/// Sends a request to the todos API from the browser, returns the todo of the response.
+async fn send(method: &str, path: &str, form: Option<FormData>) -> Result<Todo, JsValue> {
+  let window = web_sys::window().ok_or("no window")?;
+  let init = RequestInit::new();
+  init.set_method(method);
+  if let Some(form) = &form {
+    init.set_body(form);
+  }
+  let url = with_base(path, &base_path(None));
+  let res: Response = JsFuture::from(window.fetch_with_str_and_init(&url, &init))
+    .await?
+    .dyn_into()?;
+  if !res.ok() {
+    return Err(JsValue::from(res.status_text()));
+  }
+  Ok(serde_wasm_bindgen::from_value(JsFuture::from(res.json()?).await?)?)
+}
+
+/// Adds a todo to the list from the browser.
+pub async fn add(list_id: u32, title: &str) -> Result<Todo, JsValue> {
+  let form = FormData::new()?;
+  form.append_with_str("list_id", &list_id.to_string())?;
+  form.append_with_str("title", title)?;
+  send("POST", "/api/todos", Some(form)).await
+}
+
+/// Toggles the todo from the browser.
+pub async fn toggle(todo: &Todo) -> Result<Todo, JsValue> {
+  let form = FormData::new()?;
+  form.append_with_str("completed", &(!todo.completed).to_string())?;
+  send("PATCH", &format!("/api/todos/{}", todo.id), Some(form)).await
+}
+
+/// Deletes the todo from the browser.
+pub async fn remove(id: u32) -> Result<Todo, JsValue> {
+  send("DELETE", &format!("/api/todos/{}", id), None).await
+}
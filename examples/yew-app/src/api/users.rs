@@ -0,0 +1,103 @@
+//! The `/api/users` routes, a port of `examples/api-app/routes/users`.
+
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use web_sys::Request;
+
+use crate::aleph::api::{delete, form_data, get, patch, ApiError, Context, Json};
+use crate::aleph::params::{FromParams, Params, ParamsError};
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+  pub uid: u32,
+  pub name: String,
+  pub created_at: String,
+}
+
+impl User {
+  /// Validates the name sent by a form.
+  pub fn validate_name(name: Option<String>) -> Result<String, ApiError> {
+    match name {
+      Some(name) if !name.trim().is_empty() => Ok(name.trim().to_owned()),
+      _ => Err(ApiError::bad_request("invalidName", "invalid name")),
+    }
+  }
+}
+
+thread_local! {
+  static USERS: RefCell<Vec<User>> = RefCell::new(
+    [
+      (1, "john doe", "2020-01-01T00:00:00.000Z"),
+      (2, "mike johnson", "2020-01-02T00:00:00.000Z"),
+      (3, "mary jane", "2020-01-03T00:00:00.000Z"),
+      (4, "larry wall", "2020-01-04T00:00:00.000Z"),
+    ]
+    .into_iter()
+    .map(|(uid, name, created_at)| User {
+      uid,
+      name: name.to_owned(),
+      created_at: created_at.to_owned(),
+    })
+    .collect(),
+  );
+}
+
+/// The params of the `/api/users/:uid` routes.
+struct UserParams {
+  uid: u32,
+}
+
+impl FromParams for UserParams {
+  fn from_params(params: &Params) -> Result<Self, ParamsError> {
+    Ok(Self { uid: params.get("uid")? })
+  }
+}
+
+fn user_not_found() -> ApiError {
+  ApiError::not_found("userNotFound", "user not found")
+}
+
+/// Returns the uid of the route, a malformed uid is an unknown user.
+fn uid(ctx: &Context) -> Result<u32, ApiError> {
+  ctx
+    .parse_params::<UserParams>()
+    .map(|params| params.uid)
+    .map_err(|_| user_not_found())
+}
+
+#[get("/api/users")]
+pub async fn list_users(_req: Request, _ctx: Context) -> Json<Vec<User>> {
+  Json(USERS.with(|users| users.borrow().clone()))
+}
+
+#[get("/api/users/:uid")]
+pub async fn get_user(_req: Request, ctx: Context) -> Result<Json<User>, ApiError> {
+  let uid = uid(&ctx)?;
+  USERS
+    .with(|users| users.borrow().iter().find(|user| user.uid == uid).cloned())
+    .map(Json)
+    .ok_or_else(user_not_found)
+}
+
+#[patch("/api/users/:uid")]
+pub async fn update_user(req: Request, ctx: Context) -> Result<Json<User>, ApiError> {
+  let uid = uid(&ctx)?;
+  let name = User::validate_name(form_data(&req).await?.get("name").as_string())?;
+  USERS.with(|users| {
+    let mut users = users.borrow_mut();
+    let user = users.iter_mut().find(|user| user.uid == uid).ok_or_else(user_not_found)?;
+    user.name = name;
+    Ok(Json(user.clone()))
+  })
+}
+
+#[delete("/api/users/:uid")]
+pub async fn delete_user(_req: Request, ctx: Context) -> Result<Json<User>, ApiError> {
+  let uid = uid(&ctx)?;
+  USERS.with(|users| {
+    let mut users = users.borrow_mut();
+    let index = users.iter().position(|user| user.uid == uid).ok_or_else(user_not_found)?;
+    Ok(Json(users.remove(index)))
+  })
+}
@@ -1,39 +1,93 @@
-use std::collections::HashMap;
 use url::Url;
 use yew::prelude::*;
 use yew_router::history::{AnyHistory, History, MemoryHistory};
 use yew_router::prelude::*;
 
-use crate::components::header::Header;
+use crate::aleph::context::SSRContext;
+use crate::aleph::error::SSRError;
+use crate::aleph::query::SSRFragment;
+use crate::aleph::redirect::SSRRedirect;
+use crate::aleph::router::{base_path, strip_base, with_base, RouteProvider};
+use crate::aleph::session::SSRSession;
+use crate::routes::_app::AppShell;
 use crate::routes::{switch, Route};
 
 #[derive(Properties, PartialEq, Default)]
 pub struct AppProps {
-  pub ssr_url: Option<String>,
+  pub ssr_context: Option<SSRContext>,
+  /// The history of the request being rendered on the server.
+  pub ssr_history: Option<AnyHistory>,
+  /// The redirect requested by the routes on the server.
+  pub ssr_redirect: SSRRedirect,
+  /// The session values read by the routes on the server.
+  pub ssr_session: SSRSession,
+  /// Whether the app is rendered by the streaming `ssr_stream` export.
+  pub streaming: bool,
+}
+
+impl AppProps {
+  /// Creates the props to render the request of the given context on the server.
+  pub fn from_ssr_context(ctx: SSRContext, streaming: bool) -> Result<Self, SSRError> {
+    let url = Url::parse(&ctx.url().href()).map_err(|err| SSRError::BadRequest(err.to_string()))?;
+    // keeps the order and the repeated keys of the query, so the histories of the server and
+    // the browser agree
+    let query = url.query_pairs().into_owned().collect::<Vec<(String, String)>>();
+    let fragment = SSRFragment(url.fragment().unwrap_or_default().to_owned());
+    // the request path may come without the base path from a proxy, the routes match the
+    // browser path under it
+    let base_path = ctx.base_path();
+    let path = with_base(strip_base(url.path(), &base_path), &base_path);
+    let history = AnyHistory::from(MemoryHistory::new());
+    history
+      .push_with_query_and_state(path, query, fragment)
+      .map_err(|err| SSRError::Internal(err.to_string()))?;
+    Ok(Self {
+      ssr_context: Some(ctx),
+      ssr_history: Some(history),
+      ssr_redirect: SSRRedirect::default(),
+      ssr_session: SSRSession::default(),
+      streaming,
+    })
+  }
 }
 
 #[function_component]
 pub fn App(props: &AppProps) -> Html {
-  if let Some(url) = &props.ssr_url {
-    let history = AnyHistory::from(MemoryHistory::new());
-    let url = Url::parse(url).unwrap();
-    let mut queries: HashMap<String, String> = HashMap::new();
-    for (key, value) in url.query_pairs() {
-      queries.insert(key.into(), value.into());
-    }
-    history.push_with_query(url.path(), queries).unwrap();
+  let base_path = base_path(props.ssr_context.as_ref());
+  let basename = if base_path.is_empty() { None } else { Some(base_path) };
+  let router = if let Some(history) = &props.ssr_history {
     html! {
-      <Router history={history}>
-        <Header/>
-        <Switch<Route> render={switch} />
-     </Router>
+      <Router history={history.clone()} {basename}>
+        <RouteProvider>
+          <AppShell>
+            if props.streaming {
+              // flushes the rest of `index.html` once the shell is sent, removed by the renderer
+              <template data-suspense-marker="" />
+            }
+            <Switch<Route> render={switch} />
+          </AppShell>
+        </RouteProvider>
+      </Router>
     }
   } else {
     html! {
-      <BrowserRouter>
-        <Header/>
-        <Switch<Route> render={switch} />
+      <BrowserRouter {basename}>
+        <RouteProvider>
+          <AppShell>
+            <Switch<Route> render={switch} />
+          </AppShell>
+        </RouteProvider>
       </BrowserRouter>
     }
+  };
+
+  html! {
+    <ContextProvider<Option<SSRContext>> context={props.ssr_context.clone()}>
+      <ContextProvider<SSRRedirect> context={props.ssr_redirect.clone()}>
+        <ContextProvider<SSRSession> context={props.ssr_session.clone()}>
+          {router}
+        </ContextProvider<SSRSession>>
+      </ContextProvider<SSRRedirect>>
+    </ContextProvider<Option<SSRContext>>>
   }
 }
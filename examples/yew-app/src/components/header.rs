@@ -1,8 +1,37 @@
+use serde::{Deserialize, Serialize};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+use crate::aleph::session::use_session;
 use crate::routes::Route;
 
+/// The user placed in the session by the GitHub OAuth middleware.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct User {
+  login: String,
+  avatar_url: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct SessionStore {
+  user: Option<User>,
+}
+
+#[function_component]
+fn UserInfo() -> HtmlResult {
+  let session = use_session::<SessionStore>()?;
+
+  Ok(match session.as_ref().and_then(|session| session.user.clone()) {
+    Some(user) => html! {
+      <span class="user">
+        <img src={user.avatar_url} alt={user.login.clone()} />
+        {user.login}
+      </span>
+    },
+    None => Html::default(),
+  })
+}
+
 #[function_component]
 pub fn Header() -> Html {
   html! {
@@ -27,6 +56,9 @@ pub fn Header() -> Html {
           </Link<Route>>
         </h1>
         <nav>
+          <Suspense>
+            <UserInfo />
+          </Suspense>
           <a
             href="https://deno.land/x/aleph"
           >
@@ -1,7 +1,11 @@
+pub mod aleph;
+mod api;
 mod app;
 mod components;
+mod middlewares;
 mod routes;
 
+use futures::StreamExt;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -9,10 +13,57 @@ pub fn main() {
   yew::Renderer::<app::App>::new().hydrate();
 }
 
+/// Returns the route table of the app for Aleph's router.
 #[wasm_bindgen]
-pub async fn ssr(url: String) -> Result<JsValue, JsValue> {
-  let html = yew::ServerRenderer::<app::App>::with_props(app::AppProps { ssr_url: Some(url) })
-    .render()
-    .await;
-  Ok(serde_wasm_bindgen::to_value(&html).unwrap())
+pub fn routes() -> Result<JsValue, JsValue> {
+  aleph::router::route_metas::<routes::Route>()
+}
+
+/// Returns the API routes of the app and their handlers for Aleph's router.
+#[wasm_bindgen]
+pub fn api_routes() -> Result<JsValue, JsValue> {
+  api::router().into_js()
+}
+
+/// Returns the middlewares of the app for the `middlewares` option of the server.
+#[wasm_bindgen]
+pub fn middlewares() -> js_sys::Array {
+  middlewares::middlewares()
+}
+
+/// Returns the paths of the parameterised routes to pre-render.
+#[wasm_bindgen]
+pub fn static_paths() -> js_sys::Array {
+  aleph::router::static_paths::<routes::Route>()
+}
+
+#[wasm_bindgen]
+pub async fn ssr(ctx: aleph::context::SSRContext) -> Result<JsValue, JsValue> {
+  let props = app::AppProps::from_ssr_context(ctx.clone(), false)?;
+  let redirect = props.ssr_redirect.clone();
+  let session = props.ssr_session.clone();
+  let render = yew::ServerRenderer::<app::App>::with_props(props).render();
+  let mut html = aleph::abort::abortable(&ctx.signal(), render).await?;
+  if let Some(res) = redirect.response()? {
+    return Err(res.into());
+  }
+  html.push_str(&session.script());
+  Ok(html.into())
+}
+
+#[wasm_bindgen]
+pub async fn ssr_stream(ctx: aleph::context::SSRContext) -> Result<JsValue, JsValue> {
+  let props = app::AppProps::from_ssr_context(ctx.clone(), true)?;
+  let redirect = props.ssr_redirect.clone();
+  let session = props.ssr_session.clone();
+  // the session read by the suspended routes is known once the app is rendered
+  let chunks = yew::ServerRenderer::<app::App>::with_props(props)
+    .render_stream()
+    .chain(futures::stream::once(async move { session.script() }));
+  let body = aleph::stream::into_readable_stream(&ctx, chunks).await?;
+  // the html above the first suspension is rendered, the rest of the stream is dropped on redirect
+  if let Some(res) = redirect.response()? {
+    return Err(res.into());
+  }
+  Ok(body.into_raw().into())
 }
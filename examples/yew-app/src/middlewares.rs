@@ -0,0 +1,25 @@
+use js_sys::{Array, Date};
+use web_sys::Request;
+
+use crate::aleph::api::{Context, HandlerFuture};
+use crate::aleph::middleware::{into_js, with_header, Middleware};
+
+/// Adds the `X-Response-Time` header with the time taken by the rest of the chain.
+struct ResponseTime;
+
+impl Middleware for ResponseTime {
+  const NAME: &'static str = "response-time";
+
+  fn fetch(_req: Request, ctx: Context) -> HandlerFuture {
+    Box::pin(async move {
+      let start = Date::now();
+      let res = ctx.next().await?;
+      with_header(res, "X-Response-Time", &format!("{:.1}ms", Date::now() - start))
+    })
+  }
+}
+
+/// The middlewares of the app, in the order they are called.
+pub fn middlewares() -> Array {
+  [into_js::<ResponseTime>()].iter().collect()
+}
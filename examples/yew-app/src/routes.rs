@@ -1,11 +1,15 @@
 pub mod _404;
+pub mod _app;
 pub mod index;
 pub mod todos;
+pub mod user_post;
 
 use yew::prelude::*;
 use yew_router::prelude::*;
+use crate::aleph::router::StaticPaths;
 use index::Index;
-use todos::Todos;
+use todos::{TodoListPage, Todos, TodosLayout};
+use user_post::UserPost;
 use _404::NotFound;
 
 #[derive(Clone, Routable, PartialEq)]
@@ -14,15 +18,31 @@ pub enum Route {
   Home,
   #[at("/todos")]
   Todos,
+  // the params are parsed by the pages with `use_params`, so they agree with `ctx.params`
+  #[at("/todos/:list_id")]
+  TodoList { list_id: String },
+  #[at("/users/:uid/posts/:post_id")]
+  UserPost { uid: String, post_id: String },
   #[at("/404")]
   #[not_found]
   NotFound,
 }
 
+impl StaticPaths for Route {
+  fn static_paths() -> Vec<Self> {
+    // the todo lists change at runtime through the API, so their pages are rendered on request
+    // and excluded from the SSG in `server.ts`
+    Vec::new()
+  }
+}
+
 pub fn switch(routes: Route) -> Html {
   match routes {
     Route::Home => html! { <Index /> },
-    Route::Todos => html! { <Todos /> },
+    // the todo lists share the `/todos` layout, which renders them in its outlet
+    Route::Todos => html! { <TodosLayout><Todos /></TodosLayout> },
+    Route::TodoList { .. } => html! { <TodosLayout><TodoListPage /></TodosLayout> },
+    Route::UserPost { .. } => html! { <UserPost /> },
     Route::NotFound => html! { <NotFound/> },
   }
 }
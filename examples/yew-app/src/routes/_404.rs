@@ -1,10 +1,15 @@
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+use crate::aleph::head::{use_head, HeadTag};
+use crate::aleph::status::use_status;
 use crate::routes::Route;
 
 #[function_component]
 pub fn NotFound() -> Html {
+  use_status(404);
+  use_head(vec![HeadTag::title("Not Found - Yew")]);
+
   html! {
     <div class="screen e404">
       <h2>
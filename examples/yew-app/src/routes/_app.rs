@@ -0,0 +1,23 @@
+use yew::prelude::*;
+
+use crate::aleph::router::Outlet;
+use crate::components::header::Header;
+
+#[derive(Properties, PartialEq)]
+pub struct AppShellProps {
+  #[prop_or_default]
+  pub children: Children,
+}
+
+/// The `_app` route, the shell of every page.
+#[function_component]
+pub fn AppShell(props: &AppShellProps) -> Html {
+  html! {
+    <>
+      <Header/>
+      <Outlet>
+        {props.children.clone()}
+      </Outlet>
+    </>
+  }
+}
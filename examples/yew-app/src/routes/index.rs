@@ -1,10 +1,19 @@
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+use crate::aleph::head::{use_head, HeadTag};
 use crate::routes::Route;
 
 #[function_component]
 pub fn Index() -> Html {
+  use_head(vec![
+    HeadTag::title("Yew - Aleph.js"),
+    HeadTag::meta("description", "The Fullstack Framework in Deno."),
+    HeadTag::property("og:title", "Yew - Aleph.js"),
+    HeadTag::property("og:description", "The Fullstack Framework in Deno."),
+    HeadTag::link("canonical", "/"),
+  ]);
+
   html! {
     <div class="index screen">
       <p class="logo">
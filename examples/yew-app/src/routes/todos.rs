@@ -1,18 +1,24 @@
+use std::rc::Rc;
+use std::str::FromStr;
+use wasm_bindgen_futures::spawn_local;
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
+use yew_router::prelude::*;
 
-#[derive(Clone, Debug, PartialEq)]
-struct Todo {
-  id: usize,
-  completed: bool,
-  title: String,
-}
+use crate::aleph::data::{use_deferred_route_data, use_route_data};
+use crate::aleph::head::{use_head, HeadTag};
+use crate::aleph::params::{use_params, FromParams, Params, ParamsError};
+use crate::aleph::query::{use_query, FromQuery, Query, QueryError};
+use crate::aleph::router::Outlet;
+use crate::api::todos::{self as api, Todo, TodoListsData, TodosData, DEFAULT_LIST_ID};
+use crate::routes::_404::NotFound;
+use crate::routes::Route;
 
 #[derive(PartialEq, Properties, Clone)]
 struct EntryProps {
   pub todo: Todo,
-  pub ontoggle: Callback<usize>,
-  pub onremove: Callback<usize>,
+  pub ontoggle: Callback<Todo>,
+  pub onremove: Callback<u32>,
 }
 
 #[function_component]
@@ -25,7 +31,8 @@ fn Entry(props: &EntryProps) -> Html {
   };
   let ontoggle = {
     let ontoggle = props.ontoggle.clone();
-    move |_| ontoggle.emit(id)
+    let todo = props.todo.clone();
+    move |_| ontoggle.emit(todo.clone())
   };
   let onremove = {
     let onremove = props.onremove.clone();
@@ -41,68 +48,238 @@ fn Entry(props: &EntryProps) -> Html {
   }
 }
 
+/// The params of the `/todos/:list_id` route.
+struct TodoListParams {
+  list_id: u32,
+}
+
+impl FromParams for TodoListParams {
+  fn from_params(params: &Params) -> Result<Self, ParamsError> {
+    Ok(Self {
+      list_id: params.get("list_id")?,
+    })
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TodoStatus {
+  Active,
+  Completed,
+}
+
+impl FromStr for TodoStatus {
+  type Err = ();
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "active" => Ok(TodoStatus::Active),
+      "completed" => Ok(TodoStatus::Completed),
+      _ => Err(()),
+    }
+  }
+}
+
+/// The `?show=active&show=completed` filter of the todo lists, all todos are shown by default.
+#[derive(Default)]
+struct TodosFilter {
+  show: Vec<TodoStatus>,
+}
+
+impl TodosFilter {
+  fn shows(&self, todo: &Todo) -> bool {
+    let status = if todo.completed {
+      TodoStatus::Completed
+    } else {
+      TodoStatus::Active
+    };
+    self.show.is_empty() || self.show.contains(&status)
+  }
+}
+
+impl FromQuery for TodosFilter {
+  fn from_query(query: &Query) -> Result<Self, QueryError> {
+    Ok(Self {
+      show: query.get_all("show")?,
+    })
+  }
+}
+
+#[derive(PartialEq, Properties)]
+struct TodoListProps {
+  title: String,
+  /// The list of the page, the `/todos` page shows the todos of every list.
+  #[prop_or_default]
+  list_id: Option<u32>,
+}
+
+/// The todos of the page, changed with the responses of the todos API.
+#[derive(PartialEq)]
+struct TodosState(Vec<Todo>);
+
+enum TodosAction {
+  Load(Vec<Todo>),
+  Add(Todo),
+  Update(Todo),
+  Remove(u32),
+}
+
+impl Reducible for TodosState {
+  type Action = TodosAction;
+
+  fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+    let mut todos = self.0.clone();
+    match action {
+      TodosAction::Load(loaded) => todos = loaded,
+      TodosAction::Add(todo) => todos.push(todo),
+      TodosAction::Update(todo) => {
+        if let Some(t) = todos.iter_mut().find(|t| t.id == todo.id) {
+          *t = todo;
+        }
+      }
+      TodosAction::Remove(id) => todos.retain(|t| t.id != id),
+    }
+    Rc::new(Self(todos))
+  }
+}
+
+#[derive(PartialEq, Properties)]
+pub struct TodosLayoutProps {
+  #[prop_or_default]
+  pub children: Children,
+}
+
+/// The layout of the `/todos` routes, it loads the todo lists for the navigation and renders
+/// the todos in its outlet.
+#[function_component]
+pub fn TodosLayout(props: &TodosLayoutProps) -> Html {
+  let data = use_route_data::<TodoListsData>();
+  let lists = data.map(|data| data.lists.clone()).unwrap_or_default();
+
+  html! {
+    <>
+      <nav class="todos-lists">
+        <Link<Route> to={Route::Todos}>{"All"}</Link<Route>>
+        { for lists.into_iter().map(|list| html! {
+          <Link<Route> to={Route::TodoList { list_id: list.id.to_string() }}>{list.title}</Link<Route>>
+        }) }
+      </nav>
+      <Outlet>
+        {props.children.clone()}
+      </Outlet>
+    </>
+  }
+}
+
 #[function_component]
 pub fn Todos() -> Html {
-  let todos = use_state(|| Vec::<Todo>::new());
-  let all_todos = use_memo(|todos| todos.len(), todos.clone());
-  let completed_todos = use_memo(|todos| todos.iter().filter(|t| t.completed).count(), todos.clone());
+  html! { <TodosPage title="Todos" /> }
+}
+
+#[function_component]
+pub fn TodoListPage() -> Html {
+  match use_params::<TodoListParams>() {
+    Ok(params) => html! {
+      <TodosPage title={format!("Todos #{}", params.list_id)} list_id={params.list_id} />
+    },
+    Err(_) => html! { <NotFound /> },
+  }
+}
+
+#[function_component]
+fn TodosPage(props: &TodoListProps) -> Html {
+  use_head(vec![HeadTag::title(format!("{} - Yew", props.title))]);
+
+  let fallback = html! {
+    <div class="todos-app">
+      <h1><span>{&props.title}</span></h1>
+      <p>{"Loading..."}</p>
+    </div>
+  };
+
+  html! {
+    <Suspense {fallback}>
+      <TodoList title={props.title.clone()} list_id={props.list_id} />
+    </Suspense>
+  }
+}
+
+#[function_component]
+fn TodoList(props: &TodoListProps) -> HtmlResult {
+  let data = use_deferred_route_data::<TodosData>()?;
+  let todos = {
+    let data = data.clone();
+    use_reducer_eq(move || TodosState(data.map(|data| data.todos.clone()).unwrap_or_default()))
+  };
+  {
+    let todos = todos.dispatcher();
+    use_effect_with_deps(
+      move |data: &Option<Rc<TodosData>>| {
+        if let Some(data) = data {
+          todos.dispatch(TodosAction::Load(data.todos.clone()));
+        }
+        || ()
+      },
+      data,
+    );
+  }
+  let all_todos = todos.0.len();
+  let completed_todos = todos.0.iter().filter(|t| t.completed).count();
   let input_node_ref = use_node_ref();
+  let filter = use_query::<TodosFilter>().unwrap_or_default();
 
   let onadd = {
-    let todos = todos.clone();
+    let todos = todos.dispatcher();
     let input_node_ref = input_node_ref.clone();
+    let list_id = props.list_id.unwrap_or(DEFAULT_LIST_ID);
     Callback::from(move |e: FocusEvent| {
       e.prevent_default();
       let input = input_node_ref.cast::<HtmlInputElement>().unwrap();
-      let mut v = todos.to_vec();
-      v.push(Todo {
-        id: todos.to_vec().len() + 1,
-        completed: false,
-        title: input.value().trim().to_string(),
-      });
-      input.set_value("");
-      todos.set(v)
+      if let Ok(title) = Todo::validate_title(Some(input.value())) {
+        input.set_value("");
+        let todos = todos.clone();
+        spawn_local(async move {
+          if let Ok(todo) = api::add(list_id, &title).await {
+            todos.dispatch(TodosAction::Add(todo));
+          }
+        });
+      }
     })
   };
 
   let ontoggle = {
-    let todos = todos.clone();
-    Callback::from(move |id: usize| {
-      todos.set(
-        todos
-          .to_vec()
-          .into_iter()
-          .map(|t| {
-            if t.id == id {
-              Todo {
-                id: t.id,
-                completed: !t.completed,
-                title: t.title,
-              }
-            } else {
-              t
-            }
-          })
-          .collect(),
-      )
+    let todos = todos.dispatcher();
+    Callback::from(move |todo: Todo| {
+      let todos = todos.clone();
+      spawn_local(async move {
+        if let Ok(todo) = api::toggle(&todo).await {
+          todos.dispatch(TodosAction::Update(todo));
+        }
+      });
     })
   };
 
   let onremove = {
-    let todos = todos.clone();
-    Callback::from(move |id: usize| todos.set(todos.to_vec().into_iter().filter(|t| t.id != id).collect()))
+    let todos = todos.dispatcher();
+    Callback::from(move |id: u32| {
+      let todos = todos.clone();
+      spawn_local(async move {
+        if api::remove(id).await.is_ok() {
+          todos.dispatch(TodosAction::Remove(id));
+        }
+      });
+    })
   };
 
-  html! {
+  Ok(html! {
     <div class="todos-app">
       <h1>
-        <span>{"Todos"}</span>
-        if *all_todos > 0 {
+        <span>{&props.title}</span>
+        if all_todos > 0 {
           <em>{completed_todos}{"/"}{all_todos}</em>
         }
       </h1>
       <ul>
-      { for todos.iter().map(|todo| html! {
+      { for todos.0.iter().filter(|todo| filter.shows(todo)).map(|todo| html! {
         <Entry
           todo={todo.clone()}
           ontoggle={ontoggle.clone()}
@@ -121,5 +298,5 @@ pub fn Todos() -> Html {
         />
       </form>
     </div>
-  }
+  })
 }
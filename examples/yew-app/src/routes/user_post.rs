@@ -0,0 +1,41 @@
+use yew::prelude::*;
+
+use crate::aleph::head::{use_head, HeadTag};
+use crate::aleph::params::{use_params, FromParams, Params, ParamsError};
+use crate::routes::_404::NotFound;
+
+/// The params of the `/users/:uid/posts/:post_id` route.
+#[derive(Clone, PartialEq, Properties)]
+struct UserPostParams {
+  uid: String,
+  post_id: u32,
+}
+
+impl FromParams for UserPostParams {
+  fn from_params(params: &Params) -> Result<Self, ParamsError> {
+    Ok(Self {
+      uid: params.get("uid")?,
+      post_id: params.get("post_id")?,
+    })
+  }
+}
+
+#[function_component]
+pub fn UserPost() -> Html {
+  match use_params::<UserPostParams>() {
+    Ok(params) => html! { <Post ..params /> },
+    Err(_) => html! { <NotFound /> },
+  }
+}
+
+#[function_component]
+fn Post(props: &UserPostParams) -> Html {
+  use_head(vec![HeadTag::title(format!("Post #{} by {} - Yew", props.post_id, props.uid))]);
+
+  html! {
+    <div class="screen">
+      <h2>{format!("Post #{}", props.post_id)}</h2>
+      <p>{"by "}<strong>{&props.uid}</strong></p>
+    </div>
+  }
+}
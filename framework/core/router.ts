@@ -6,6 +6,7 @@ import events from "./events.ts";
 
 export type Router = {
   prefix: string;
+  basePath?: string;
   routes: RoutePattern[];
   appDir?: string; // for ssr
   _404?: RoutePattern;
@@ -141,7 +142,7 @@ export function loadRouterFromTag(): Router {
           }
           return route;
         });
-        return { routes, prefix: manifest.prefix, _app, _404 };
+        return { routes, prefix: manifest.prefix, basePath: manifest.basePath, _app, _404 };
       }
     } catch (e) {
       throw new Error(`loadRouteConfigFromTag: ${e.message}`);
@@ -52,7 +52,10 @@ export async function build(
   const { routes } = await globalIt("__ALEPH_ROUTER", () => initRouter(appDir, config.router));
 
   routes.forEach(([_, { filename }]) => {
-    routeFiles.push(filename);
+    // skip the routes defined by code
+    if (filename) {
+      routeFiles.push(filename);
+    }
   });
   if (ssgOptions && config.ssr) {
     for (const [_, { pattern }] of routes) {
@@ -295,10 +295,11 @@ export function createHandler(config: AlephConfig) {
           // inject the router manifest
           rewriter.on("head", {
             element(el) {
-              if (router.routes.length > 0) {
+              if (router.routes.length > 0 || router.basePath) {
                 const json = JSON.stringify({
                   routes: router.routes.map(([_, meta]) => meta),
                   prefix: router.prefix,
+                  basePath: router.basePath,
                 });
                 el.append(`<script id="router-manifest" type="application/json">${json}</script>`, {
                   html: true,
@@ -349,6 +350,18 @@ export function createHandler(config: AlephConfig) {
       if (err instanceof Response) {
         return err;
       }
+
+      // the client has gone, e.g. the SSR of a Rust app stopped with the 499 status
+      if (req.signal.aborted) {
+        return new Response(null, { status: 499 });
+      }
+
+      // use the `onError` if available
+      const res = onError?.(err, "ssr", req, ctx);
+      if (res instanceof Response) {
+        return res;
+      }
+
       let message: string;
       if (err instanceof Error) {
         message = err.stack as string;
@@ -356,7 +369,12 @@ export function createHandler(config: AlephConfig) {
       } else {
         message = err?.toString?.() || String(err);
       }
+      let status: number = err?.status;
+      if (!Number.isInteger(status) || status < 400) {
+        status = 500;
+      }
       return new Response(generateErrorHtml(message, "SSR"), {
+        status,
         headers: {
           "Cache-Control": "public, max-age=0, must-revalidate",
           "Content-Type": "text/html; charset=utf-8",
@@ -375,12 +393,13 @@ export function createHandler(config: AlephConfig) {
       connInfo,
       sessionOptions: config.session,
     });
-    const next = (i: number): Promise<Response> | Response => {
+    const next = async (i: number): Promise<Response> => {
       if (Array.isArray(middlewares) && i < middlewares.length) {
         const mw = middlewares[i];
         try {
           Reflect.set(ctx, NEXT, next.bind(null, i + 1));
-          return mw.fetch(req, ctx);
+          // await the response so the rejected promise of an async middleware is caught as well
+          return await mw.fetch(req, ctx);
         } catch (err) {
           const res = onError?.(err, "middleware", req, ctx);
           if (res instanceof Response) {
@@ -7,7 +7,15 @@ import depGraph from "./graph.ts";
 import { getAlephConfig, getAppDir, getDeploymentId, getFiles, regJsxFile, toLocalPath } from "./helpers.ts";
 import log from "./log.ts";
 import { importRouteModule } from "./router.ts";
-import type { HTMLRewriterHandlers, SSR, SSRContext, SuspenseMarker } from "./types.ts";
+import type {
+  Context,
+  Cookies,
+  HTMLRewriterHandlers,
+  SSR,
+  SSRContext,
+  SSRResult,
+  SuspenseMarker,
+} from "./types.ts";
 
 export type RenderOptions = {
   indexHtml: Uint8Array;
@@ -33,9 +41,14 @@ export default {
 
     const ssrContext: SSRContext = {
       url,
+      headers: req.headers,
+      cookies: ctx.cookies as Cookies,
+      params: ctx.params as Record<string, string>,
       modules,
       headCollection,
       signal: req.signal,
+      basePath: router.basePath ?? "",
+      getSession: <T extends Record<string, unknown>>() => (ctx as unknown as Context).getSession<T>(),
       setStatus: (code) => {
         status = code;
       },
@@ -57,6 +70,12 @@ export default {
     }
 
     let body = await render(ssrContext);
+    if (isPlainObject<SSRResult>(body)) {
+      if (isFilledString(body.head)) {
+        headCollection.push(body.head);
+      }
+      body = body.body;
+    }
     if (typeof body !== "string" && !(body instanceof ReadableStream)) {
       log.warn("Invalid SSR body");
       body = "";
@@ -129,10 +148,11 @@ export default {
         // inject the router manifest
         rewriter.on("head", {
           element(el) {
-            if (router.routes.length > 0) {
+            if (router.routes.length > 0 || router.basePath) {
               const json = JSON.stringify({
                 routes: router.routes.map(([_, meta]) => meta),
                 prefix: router.prefix,
+                basePath: router.basePath,
               });
               el.append(`<script id="router-manifest" type="application/json">${json}</script>`, {
                 html: true,
@@ -141,6 +161,15 @@ export default {
           },
         });
 
+        // a document has only one title, the title rendered by the app replaces the one of `index.html`
+        if (headCollection.some((h) => isFilledString(h) && /<title[\s>]/.test(h))) {
+          rewriter.on("head > title", {
+            element(el) {
+              el.remove();
+            },
+          });
+        }
+
         rewriter.on("head", {
           element(el) {
             const ssrModules = modules.map(({ url, params, filename, withData, data, dataCacheTtl }) => {
@@ -168,20 +197,23 @@ export default {
             const deployId = getDeploymentId();
             const q = deployId ? `?v=${deployId}` : "";
             el.append(
-              modules.map(({ filename }) =>
+              modules.filter(({ exports }) => exports.default !== undefined).map(({ filename }) =>
                 `<link rel="modulepreload" href="${filename.slice(1)}${q}" data-module-id="${filename}" />`
               ).join(""),
               { html: true },
             );
 
-            headCollection.forEach((h) => isFilledString(h) && el.append(h, { html: true }));
+            headCollection.forEach((h) =>
+              isFilledString(h) && el.append(nonce ? withNonce(h, nonce) : h, { html: true })
+            );
           },
         });
 
         rewriter.on("ssr-body", {
           element(el) {
             if (typeof body === "string") {
-              el.replace(body, { html: true });
+              // the inserted html is not processed by the rewriter
+              el.replace(nonce ? withNonce(body, nonce) : body, { html: true });
             } else if (body instanceof ReadableStream) {
               streamStarted = true;
               el.remove();
@@ -190,6 +222,10 @@ export default {
                 controller.enqueue(chunk);
               });
 
+              if (nonce) {
+                applyNonce(rw, nonce);
+              }
+
               if (suspenseMarker) {
                 rw.on(suspenseMarker.selector, {
                   element(el) {
@@ -236,14 +272,7 @@ export default {
         });
 
         if (nonce) {
-          rewriter.on("script", {
-            element(el) {
-              const typeAttr = el.getAttribute("type");
-              if ((!typeAttr || typeAttr === "module") && !el.getAttribute("src")) {
-                el.setAttribute("nonce", nonce!);
-              }
-            },
-          });
+          applyNonce(rewriter, nonce);
         }
 
         try {
@@ -267,6 +296,40 @@ export default {
   },
 };
 
+/** add the `nonce` attribute to the inline scripts and styles. */
+function applyNonce(rewriter: HTMLRewriter, nonce: string) {
+  rewriter.on("script", {
+    element(el) {
+      const typeAttr = el.getAttribute("type");
+      if ((!typeAttr || typeAttr === "module") && !el.getAttribute("src")) {
+        el.setAttribute("nonce", nonce);
+      }
+    },
+  });
+  rewriter.on("style", {
+    element(el) {
+      el.setAttribute("nonce", nonce);
+    },
+  });
+}
+
+/** add the `nonce` attribute to the inline scripts and styles of the html. */
+function withNonce(html: string, nonce: string): string {
+  const decoder = new TextDecoder();
+  let output = "";
+  const rewriter = new HTMLRewriter("utf8", (chunk: Uint8Array) => {
+    output += decoder.decode(chunk, { stream: true });
+  });
+  applyNonce(rewriter, nonce);
+  try {
+    rewriter.write(utf8Enc.encode(html));
+    rewriter.end();
+  } finally {
+    rewriter.free();
+  }
+  return output + decoder.decode();
+}
+
 /** import route modules and fetch data for SSR */
 async function initSSR(
   req: Request,
@@ -362,7 +425,8 @@ async function initSSR(
 
   return [
     url,
-    modules.filter(({ exports }) => exports.default !== undefined),
+    // keep the routes defined by code for the apps that render the routes themselves, e.g. the Yew app
+    modules.filter(({ filename, exports }) => exports.default !== undefined || !filename),
     deferedData,
   ];
 }
@@ -24,6 +24,11 @@ export async function importRouteModule({ filename, pattern }: RouteMeta, appDir
     return modules[pattern.pathname];
   }
 
+  // the route defined by code has no module file
+  if (!filename) {
+    return {};
+  }
+
   const version = depGraph.get(filename)?.version;
   const origin = Reflect.get(globalThis, "__ALEPH_SERVER_ORIGIN");
 
@@ -137,6 +142,19 @@ export async function initRouter(appDir: string, init: RouterInit = {}): Promise
       }
     }
   });
+  // add the routes defined by code, e.g. the route table of a Rust app
+  init.routes?.forEach((meta) => {
+    const { pathname } = meta.pattern;
+    if (routes.every(([_, { pattern }]) => pattern.pathname !== pathname)) {
+      const route: RoutePattern = [new URLPatternCompat(meta.pattern), { ...meta }];
+      routes.push(route);
+      if (pathname === "/_app") {
+        _app = route;
+      } else if (pathname === "/_404") {
+        _404 = route;
+      }
+    }
+  });
   if (routes.length > 0) {
     // sort routes by length of pathname
     routes.sort((a, b) => getRouteOrder(a) - getRouteOrder(b));
@@ -157,6 +175,7 @@ export async function initRouter(appDir: string, init: RouterInit = {}): Promise
     appDir,
     routes,
     prefix: reg.prefix,
+    basePath: init.basePath ? trimSuffix(cleanPath(init.basePath), "/") : "",
     _404,
     _app,
   };
@@ -226,7 +245,10 @@ function getRouteOrder([_, meta]: RoutePattern): number {
     case "/_404":
     case "/_app":
       return 0;
-    default:
-      return filename.split("/").length + (pattern.pathname.split("/:").length - 1) * 0.01;
+    default: {
+      // the routes defined by code have no filename, e.g. `/blog` is ordered like `./routes/blog.tsx`
+      const depth = filename ? filename.split("/").length : pattern.pathname.split("/").length + 1;
+      return depth + (pattern.pathname.split("/:").length - 1) * 0.01;
+    }
   }
 }
@@ -1,6 +1,6 @@
 import type { ConnInfo, ServeInit } from "https://deno.land/std@0.180.0/http/server.ts";
 import type { Comment, Element, TextChunk } from "https://deno.land/x/lol_html@0.0.6/types.d.ts";
-import type { RouteModule } from "../framework/core/router.ts";
+import type { RouteMeta, RouteModule } from "../framework/core/router.ts";
 
 export type AlephConfig = {
   /** The TLS options. */
@@ -67,7 +67,11 @@ export interface AtomicCSSEngine {
 
 /** The router options for the file-system based routing. */
 export interface RouterInit {
-  /** The base url of the router. Dafault is `/` */
+  /**
+   * The base url of the router. Dafault is `/`
+   *
+   * The app is served under it in the browser, e.g. behind a proxy that strips it from the requests.
+   */
   basePath?: string;
   /** The glob to match routes.  */
   glob?: string;
@@ -77,8 +81,10 @@ export interface RouterInit {
   exts?: string[];
   /** The route module URL. */
   moduleURL?: string;
-  /** The pre-built module.  */
+  /** The pre-built modules by route pathname, e.g. the API routes of a Rust app. */
   modules?: Record<string, Record<string, unknown>>;
+  /** The routes defined by code, e.g. the route table of a Rust app. The FS routes take precedence. */
+  routes?: RouteMeta[];
   /** The handler to handle the router change. */
   onChange?(): void;
 }
@@ -216,10 +222,16 @@ export type SSGOptions = {
 
 export type SSRContext = {
   readonly url: URL;
+  readonly headers: Headers;
+  readonly cookies: Cookies;
+  readonly params: Record<string, string>;
   readonly modules: RouteModule[];
   readonly headCollection: string[];
   readonly signal: AbortSignal;
   readonly nonce?: string;
+  /** The `basePath` of the router without the trailing slash, an empty string for the root. */
+  readonly basePath: string;
+  getSession<T extends Record<string, unknown> = Record<string, unknown>>(): Promise<Session<T>>;
   setStatus(code: number): void;
   setSuspenseMarker(selector: string, test: (el: Element) => boolean): void;
 };
@@ -229,8 +241,14 @@ export type SuspenseMarker = {
   test: (el: Element) => boolean;
 };
 
+/** The SSR output with the head elements collected by the app, e.g. the tags of `leptos_meta`. */
+export type SSRResult = {
+  head?: string;
+  body: ReadableStream | string;
+};
+
 export type SSRFn = {
-  (ssr: SSRContext): Promise<ReadableStream | string> | ReadableStream | string;
+  (ssr: SSRContext): Promise<ReadableStream | string | SSRResult> | ReadableStream | string | SSRResult;
 };
 
 /**
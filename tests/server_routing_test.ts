@@ -149,3 +149,54 @@ Deno.test("[unit] server/router.ts: matchRoutes", async () => {
     "./routes/users/$uid/settings/$page.tsx",
   ]);
 });
+
+Deno.test("[unit] server/router.ts: routes defined by code", async () => {
+  const tmpDir = await Deno.makeTempDir();
+  const files = [
+    "./routes/_app.tsx",
+    "./routes/blog.tsx",
+    "./routes/index.tsx",
+  ];
+  await Promise.all(files.map((file) => Deno.mkdir(join(tmpDir, dirname(file)), { recursive: true })));
+  await Promise.all(files.map((file) => Deno.writeTextFile(join(tmpDir, file), "")));
+  const routes = await initRouter(tmpDir, {
+    glob: "./routes/**/*.tsx",
+    routes: ["/todos/:list_id", "/blog", "/todos/index", "/todos", "/todos/new"].map((pathname) => ({
+      filename: "",
+      pattern: { pathname },
+    })),
+  });
+  // the `/blog` route of the file takes precedence
+  assertEquals(routes.routes.length, files.length + 4);
+  const blog = routes.routes.filter(([_, meta]) => meta.pattern.pathname === "/blog");
+  assertEquals(blog.map(([_, meta]) => meta.filename), ["./routes/blog.tsx"]);
+
+  // the routes defined by code are ordered like the files of their pathnames, the static routes come first
+  assertEquals(routes.routes.filter(([_, meta]) => !meta.filename).map(([_, meta]) => meta.pattern.pathname), [
+    "/todos",
+    "/todos/index",
+    "/todos/new",
+    "/todos/:list_id",
+  ]);
+  assertEquals(routes.routes.filter(([_, meta]) => meta.nesting).map(([_, meta]) => meta.pattern.pathname).sort(), [
+    "/_app",
+    "/todos",
+  ]);
+
+  let matches = matchRoutes(new URL("/blog", "http://localhost:3000"), routes);
+  assertEquals(matches.map(([ret]) => ret.pathname.input), ["/_app", "/blog"]);
+  assertEquals(matches.map(([_, meta]) => meta.filename), ["./routes/_app.tsx", "./routes/blog.tsx"]);
+
+  matches = matchRoutes(new URL("/todos", "http://localhost:3000"), routes);
+  assertEquals(matches.map(([ret]) => ret.pathname.input), ["/_app", "/todos", "/todos/index"]);
+  assertEquals(matches.map(([_, meta]) => meta.filename), ["./routes/_app.tsx", "", ""]);
+
+  matches = matchRoutes(new URL("/todos/new", "http://localhost:3000"), routes);
+  assertEquals(matches.map(([ret]) => ret.pathname.input), ["/_app", "/todos", "/todos/new"]);
+  assertEquals(matches.map(([ret]) => ret.pathname.groups), [{}, {}, {}]);
+
+  matches = matchRoutes(new URL("/todos/2", "http://localhost:3000"), routes);
+  assertEquals(matches.map(([ret]) => ret.pathname.input), ["/_app", "/todos", "/todos/2"]);
+  assertEquals(matches.map(([ret]) => ret.pathname.groups), [{}, {}, { list_id: "2" }]);
+  assertEquals(matches.map(([_, meta]) => meta.pattern.pathname), ["/_app", "/todos", "/todos/:list_id"]);
+});